@@ -0,0 +1,101 @@
+use rand::{
+	prelude::Rng,
+	RngCore,
+};
+
+/// Samples indices according to their weights in constant time, using Vose's
+/// alias method. Building the table takes linear time, so it's built once per
+/// provider and used for every row.
+#[derive(Debug)]
+pub struct AliasTable {
+	/// The chance of picking the index itself instead of its alias, per index
+	probabilities: Vec<f64>,
+	/// The index picked when the index itself isn't, per index
+	aliases: Vec<usize>,
+}
+
+impl AliasTable { // {{{
+	/// Creates the table for `weights`. Returns `None` when there are no
+	/// weights or they don't add up to more than 0. Weights should be finite
+	/// and not be negative.
+	pub fn new(weights: &[f64]) -> Option<Self> { // {{{
+		let total: f64 = weights.iter().sum();
+
+		if weights.is_empty() || total <= 0.0 || !total.is_finite() {
+			return None;
+		}
+
+		let count = weights.len();
+		let mut probabilities: Vec<f64> = weights.iter()
+			.map( |weight| weight * count as f64 / total )
+			.collect();
+		let mut aliases: Vec<usize> = (0..count).collect();
+		let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..count)
+			.partition( |i| probabilities[*i] < 1.0 );
+
+		while let (Some(less), Some(more)) = ( small.pop(), large.pop() ) {
+			aliases[less] = more;
+			probabilities[more] -= 1.0 - probabilities[less];
+
+			match probabilities[more] < 1.0 {
+				true => small.push(more),
+				false => large.push(more),
+			}
+		}
+
+		// whatever is left only differs from 1 by rounding errors
+		for i in small.into_iter().chain(large) {
+			probabilities[i] = 1.0;
+		}
+
+		Some( Self { probabilities, aliases } )
+	} // }}}
+
+	/// Returns a random index, each with a chance proportional to its weight
+	pub fn sample(&self, rng: &mut dyn RngCore) -> usize { // {{{
+		let i = rng.gen_range( 0..self.probabilities.len() );
+
+		match rng.gen::<f64>() < self.probabilities[i] {
+			true => i,
+			false => self.aliases[i],
+		}
+	} // }}}
+} // }}}
+
+#[cfg(test)]
+mod tests {
+	use rand::{ rngs::StdRng, SeedableRng };
+
+	use super::*;
+
+	#[test]
+	fn test_new_should_return_none_when_no_weight_is_positive() { // {{{
+		assert!( AliasTable::new(&[]).is_none() );
+		assert!( AliasTable::new(&[0.0, 0.0]).is_none() );
+	} // }}}
+
+	#[test]
+	fn test_sample_should_never_pick_weights_of_0() { // {{{
+		let sut = AliasTable::new(&[0.0, 3.0, 0.0, 1.0]).unwrap();
+		let mut rng = StdRng::seed_from_u64(42);
+
+		for _ in 0..1000 {
+			assert!( [1, 3].contains( &sut.sample(&mut rng) ) );
+		}
+	} // }}}
+
+	#[test]
+	fn test_sample_should_pick_indices_proportional_to_their_weight() { // {{{
+		let sut = AliasTable::new(&[90.0, 9.0, 1.0]).unwrap();
+		let mut rng = StdRng::seed_from_u64(42);
+		let mut counts = [0; 3];
+
+		for _ in 0..100_000 {
+			counts[ sut.sample(&mut rng) ] += 1;
+		}
+
+		assert!( (89_000..91_000).contains(&counts[0]), "{:?}", counts );
+		assert!( (8_500..9_500).contains(&counts[1]), "{:?}", counts );
+		assert!( (800..1_200).contains(&counts[2]), "{:?}", counts );
+	} // }}}
+}
@@ -2,7 +2,7 @@ use std::path::{PathBuf, Path};
 
 use clap::Parser as CliParser;
 
-const OUTPUT_TYPES_ALLOWED: &'static [&'static str] = &[
+const OUTPUT_TYPES_ALLOWED: &[&str] = &[
 	"tsql",
 	"csv",
 	"json",
@@ -10,14 +10,10 @@ const OUTPUT_TYPES_ALLOWED: &'static [&'static str] = &[
 ];
 
 fn validate_output_type(r#type: &str) -> Result<(), String> { // {{{
-	let allowed = OUTPUT_TYPES_ALLOWED.into_iter()
-		.fold(false, |acc, curr| acc || curr == &r#type);
+	let allowed = OUTPUT_TYPES_ALLOWED.contains(&r#type);
 
 	if !allowed {
-		let allowed_types = OUTPUT_TYPES_ALLOWED.into_iter()
-			.map( |t| t.to_string() )
-			.collect::< Vec<String> >()
-			.join(", ");
+		let allowed_types = OUTPUT_TYPES_ALLOWED.join(", ");
 
 		return Err( format!(
 			"valid output types are {}.",
@@ -28,6 +24,44 @@ fn validate_output_type(r#type: &str) -> Result<(), String> { // {{{
 	Ok(())
 } // }}}
 
+pub const JSON_LAYOUT_ARRAY: &str = "array";
+pub const JSON_LAYOUT_NEWLINE_DELIMITED: &str = "ndjson";
+
+const JSON_LAYOUTS_ALLOWED: &[&str] = &[
+	JSON_LAYOUT_ARRAY,
+	JSON_LAYOUT_NEWLINE_DELIMITED,
+];
+
+fn validate_json_layout(layout: &str) -> Result<(), String> { // {{{
+	let allowed = JSON_LAYOUTS_ALLOWED.contains(&layout);
+
+	if !allowed {
+		let allowed_layouts = JSON_LAYOUTS_ALLOWED.join(", ");
+
+		return Err( format!(
+			"valid json layouts are {}.",
+			allowed_layouts,
+		) );
+	}
+
+	Ok(())
+} // }}}
+
+fn validate_xml_name(name: &str) -> Result<(), String> { // {{{
+	let mut chars = name.chars();
+
+	let valid_start = chars.next()
+		.map( |c| c.is_ascii_alphabetic() || c == '_' )
+		.unwrap_or(false);
+	let valid_rest = chars.all( |c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.' );
+
+	if !valid_start || !valid_rest {
+		return Err( "not a valid xml element name.".to_string() );
+	}
+
+	Ok(())
+} // }}}
+
 fn validate_path_is_folder(path: &str) -> Result<(), String> { // {{{
 	validate_path_exists(path)?;
 
@@ -50,22 +84,115 @@ fn validate_path_exists(path: &str) -> Result<(), String> { // {{{
 	Ok(())
 } // }}}
 
+/// Splits a `NAME=value` pair given with `--define`. The value is parsed
+/// when the config is parsed.
+fn parse_define(define: &str) -> Result<(String, String), String> { // {{{
+	let (name, value) = define.split_once('=')
+		.ok_or_else( || "expected NAME=value.".to_string() )?;
+
+	let mut chars = name.chars();
+	let valid_start = chars.next()
+		.map( |c| c.is_ascii_uppercase() )
+		.unwrap_or(false);
+	let valid_rest = chars.all( |c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_' );
+
+	if !valid_start || !valid_rest {
+		return Err( "constant names are written in upper case, like MAX_SALARY.".to_string() );
+	}
+
+	Ok( ( name.to_string(), value.to_string() ) )
+} // }}}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Command {
+	/// Checks the config for errors without generating any data
+	Check {
+		/// The path to the config file, or a directory of config files
+		#[clap(validator = validate_path_exists)]
+		config: PathBuf,
+	},
+}
+
 #[derive(CliParser, Debug)]
 #[clap(author, version, about, long_about = None)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Args {
+	#[clap(subcommand)]
+	pub command: Option<Command>,
+
 	/// The amount of rows to create for each table
 	#[clap(short = 'c', long, default_value_t = 1000)]
 	pub row_count: usize,
 
+	/// The amount of times a provider is asked for a new value for a single
+	/// cell when the constraints of the column keep rejecting its values, for
+	/// example because they have to be unique
+	#[clap(long, default_value_t = 1000)]
+	pub max_attempts: usize,
+
+	/// The seed used to generate random values. Running with the same seed and
+	/// config results in the same output
+	#[clap(short, long)]
+	pub seed: Option<u64>,
+
 	/// The output type to use when generating the mock data
 	#[clap(short, long, default_value = "tsql", validator = validate_output_type)]
 	pub r#type: String,
 
+	/// The character used to separate the fields when generating csv
+	#[clap(long, default_value_t = ',')]
+	pub csv_delimiter: char,
+
+	/// The character used to quote fields when generating csv
+	#[clap(long, default_value_t = '"')]
+	pub csv_quote: char,
+
+	/// Write json without any indentation or line breaks
+	#[clap(long)]
+	pub json_compact: bool,
+
+	/// The layout of json output. `array` writes one array containing all
+	/// rows, `ndjson` writes one object per line
+	#[clap(long, default_value = JSON_LAYOUT_ARRAY, validator = validate_json_layout)]
+	pub json_layout: String,
+
+	/// The name of the root element of xml output
+	#[clap(long, default_value = "data", validator = validate_xml_name)]
+	pub xml_root: String,
+
+	/// The name of the element containing a single row in xml output
+	#[clap(long, default_value = "entry", validator = validate_xml_name)]
+	pub xml_row: String,
+
+	/// Write the columns of xml output as attributes of the row element
+	/// instead of as child elements
+	#[clap(long)]
+	pub xml_attributes: bool,
+
+	/// Add a check constraint to every column of an enum type in tsql output,
+	/// so the column only accepts the variants of the enum
+	#[clap(long)]
+	pub tsql_enum_checks: bool,
+
+	/// Replaces the value of a constant declared with `let NAME = value;` in
+	/// the config. Can be given multiple times
+	#[clap(
+		short = 'D',
+		long = "define",
+		value_name = "NAME=VALUE",
+		global = true,
+		multiple_occurrences = true,
+		number_of_values = 1,
+		parse(try_from_str = parse_define),
+	)]
+	pub defines: Vec<(String, String)>,
+
 	/// The path to the output folder
-	#[clap(short, long, validator = validate_path_is_folder)]
-	pub output: String,
+	#[clap(short, long, required = true, validator = validate_path_is_folder)]
+	pub output: Option<String>,
 
-	/// The path to the config file
-	#[clap(validator = validate_path_exists)]
-	pub config: PathBuf,
+	/// The path to the config file, or a directory of config files. Every
+	/// `.mock` file in the directory is used
+	#[clap(required = true, validator = validate_path_exists)]
+	pub config: Option<PathBuf>,
 }
@@ -0,0 +1,116 @@
+use rand::{
+	rngs::mock::StepRng,
+	RngCore,
+};
+use thiserror::Error;
+
+use crate::{
+	generator::CellValue,
+	parameter::Parameter,
+	parser::config::Argument,
+	rng::column_rng,
+};
+
+#[derive(Debug, Error)]
+#[cfg_attr( test, derive(PartialEq) )]
+pub enum ConstraintError {
+	/// Used when constraint {0} isn't registered under its name
+	#[error("Unknown constraint '{0}'")]
+	UnknownConstraint(String),
+
+	/// Used when an argument given to a [`ConstraintImpl`] is not correct.
+	/// `Unexpected {0}, expected {1}`
+	#[error("Unexpected argument '{0}', expected {1}")]
+	UnexpectedArgument(String, String),
+
+	/// Used when a constraint that doesn't allow null values receives one
+	#[error("Null values are not allowed by constraint '{0}'")]
+	NullNotAllowed(String),
+
+	/// Used when constraint {0} can't be used on the same column as constraint
+	/// {1}. {2} describes why
+	#[error("Constraint '{0}' can't be used together with '{1}', because {2}")]
+	ConflictingConstraints(String, String, String),
+}
+
+/// The data a constraint is created from. Every column gets its own
+/// constraints, so this describes a single constraint of a single column.
+#[derive(Default)]
+pub struct ConstraintCreationData {
+	/// The name of the table the column belongs to
+	pub table: String,
+	/// The name of the column the constraint is applied to
+	pub column: String,
+	/// The name of the constraint
+	pub name: String,
+	/// The arguments given to the constraint in the config
+	pub arguments: Vec<Argument>,
+	/// The seed used to create the random number generators of the
+	/// constraints. `None` when the output should be random every time.
+	pub seed: Option<u64>,
+}
+
+impl ConstraintCreationData { // {{{
+	/// Creates the random number generator the constraint should use. Returns
+	/// predictable values in tests.
+	pub fn rng(&self) -> Box<dyn RngCore> {
+		if cfg!(test) {
+			return Box::new( StepRng::new(0, 1) );
+		}
+
+		// prefix the constraint, so it doesn't share its values with the
+		// provider of the column
+		column_rng( self.seed, &[&self.table, &self.column, "$", &self.name] )
+	}
+
+	/// Creates the data of a constraint that only gets `arguments`, for
+	/// testing constraints without a config
+	#[cfg(test)]
+	pub fn with_arguments(arguments: Vec<Argument>) -> Self {
+		Self {
+			arguments,
+			..Self::default()
+		}
+	}
+} // }}}
+
+pub trait ConstraintImpl { // {{{
+	/// Used to create a new constraint for a single column. Should parse the
+	/// arguments of the constraint.
+	///
+	/// # Arguments
+	///
+	/// - `data` The column and constraint the constraint is created for
+	fn new(data: &ConstraintCreationData) -> Result<Self, ConstraintError>
+		where Self: Sized;
+
+	/// Returns the parameters of the constraint. The arguments given in the
+	/// config are checked against them and passed to `new()` in the order of
+	/// the parameters. Constraints without parameters don't accept any
+	/// arguments.
+	fn parameters() -> Vec<Parameter>
+		where Self: Sized
+	{
+		Vec::new()
+	}
+
+	/// Whether the constraint requires every value in the column to be unique.
+	/// Used to detect providers that can't provide enough distinct values
+	/// before generating the column.
+	fn requires_unique_values(&self) -> bool {
+		false
+	}
+
+	/// The percentage of values the constraint replaces with null. Nulls don't
+	/// have to be unique, so they're subtracted from the distinct values a
+	/// column needs.
+	fn null_percentage(&self) -> f64 {
+		0.0
+	}
+
+	/// Gets called every time a provider created a value for a cell. Should
+	/// return the value that ends up in the cell, which can be `value` itself
+	/// or a replacement. Returns `None` when the value is rejected, after which
+	/// the provider is asked for a new value.
+	fn apply(&mut self, value: CellValue) -> Result<Option<CellValue>, ConstraintError>;
+} // }}}
@@ -0,0 +1,3 @@
+pub mod null;
+pub mod primary;
+pub mod unique;
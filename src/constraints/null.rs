@@ -0,0 +1,154 @@
+use rand::{
+	prelude::Rng,
+	RngCore,
+};
+
+use crate::{
+	constraint::{
+		ConstraintCreationData,
+		ConstraintError,
+		ConstraintImpl,
+	},
+	generator::CellValue,
+	parameter::{ Parameter, ParameterKind },
+	parser::config::Argument,
+};
+
+pub const NULL_CONSTRAINT_NAME: &str = "null";
+
+const DEFAULT_PERCENTAGE: f64 = 100.0;
+
+pub struct NullConstraint {
+	rng: Box<dyn RngCore>,
+	percentage: f64,
+}
+
+impl NullConstraint {
+	fn parse_percentage_from_arg(arg: &Argument) -> Result<f64, ConstraintError> {
+		let percentage = match arg {
+			Argument::Int(value) => *value as f64,
+			Argument::Float(value) => *value,
+
+			arg => return Err( ConstraintError::UnexpectedArgument(
+				arg.to_string(),
+				"int or float".to_string(),
+			) ),
+		};
+
+		if !(0.0..=100.0).contains(&percentage) {
+			return Err( ConstraintError::UnexpectedArgument(
+				arg.to_string(),
+				"a percentage between 0 and 100".to_string(),
+			) );
+		}
+
+		Ok(percentage)
+	}
+}
+
+impl ConstraintImpl for NullConstraint {
+	fn new(data: &ConstraintCreationData) -> Result<Self, ConstraintError> {
+		let percentage = match data.arguments.first() {
+			Some(percentage) => Self::parse_percentage_from_arg(percentage)?,
+			None => DEFAULT_PERCENTAGE,
+		};
+
+		Ok( Self {
+			rng: data.rng(),
+			percentage,
+		} )
+	}
+
+	fn parameters() -> Vec<Parameter> {
+		vec![
+			Parameter::new("percentage", ParameterKind::Number).with_default( Argument::Int(100) ),
+		]
+	}
+
+	fn null_percentage(&self) -> f64 {
+		self.percentage
+	}
+
+	fn apply(&mut self, value: CellValue) -> Result<Option<CellValue>, ConstraintError> {
+		if self.rng.gen_bool(self.percentage / 100.0) {
+			return Ok( Some(CellValue::Null) );
+		}
+
+		Ok( Some(value) )
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_apply_should_return_null_when_the_percentage_is_hit() -> Result<(), ConstraintError> { // {{{
+		let mut sut = NullConstraint::new( &ConstraintCreationData::with_arguments( vec![ Argument::Int(25) ] ) )?;
+
+		let result = sut.apply( CellValue::Int(5) )?;
+
+		assert_eq!( Some(CellValue::Null), result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_apply_should_return_the_value_when_the_percentage_is_0() -> Result<(), ConstraintError> { // {{{
+		let mut sut = NullConstraint::new( &ConstraintCreationData::with_arguments( vec![ Argument::Int(0) ] ) )?;
+
+		let result = sut.apply( CellValue::Int(5) )?;
+
+		assert_eq!( Some( CellValue::Int(5) ), result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_set_percentage_to_default_when_no_arguments_are_given() -> Result<(), ConstraintError> { // {{{
+		let sut = NullConstraint::new( &ConstraintCreationData::with_arguments( vec![] ) )?;
+
+		assert_eq!(DEFAULT_PERCENTAGE, sut.percentage);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_accept_a_float_percentage() -> Result<(), ConstraintError> { // {{{
+		let sut = NullConstraint::new( &ConstraintCreationData::with_arguments( vec![ Argument::Float(12.5) ] ) )?;
+
+		assert_eq!(12.5, sut.percentage);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_the_percentage_is_out_of_range() -> Result<(), ConstraintError> { // {{{
+		let expected = Err( ConstraintError::UnexpectedArgument(
+			"101".to_string(),
+			"a percentage between 0 and 100".to_string(),
+		) );
+
+		let result = NullConstraint::new( &ConstraintCreationData::with_arguments( vec![ Argument::Int(101) ] ) )
+			.map( |sut| sut.percentage );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_no_number_is_given() -> Result<(), ConstraintError> { // {{{
+		let expected = Err( ConstraintError::UnexpectedArgument(
+			"abc".to_string(),
+			"int or float".to_string(),
+		) );
+
+		let result = NullConstraint::new( &ConstraintCreationData::with_arguments( vec![ Argument::String( "abc".to_string() ) ] ) )
+			.map( |sut| sut.percentage );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+}
@@ -0,0 +1,76 @@
+use crate::{
+	constraint::{
+		ConstraintCreationData,
+		ConstraintError,
+		ConstraintImpl,
+	},
+	constraints::unique::UniqueConstraint,
+	generator::CellValue,
+};
+
+pub const PRIMARY_CONSTRAINT_NAME: &str = "primary";
+
+/// Marks a column as the key of its table. Every value in the column has to
+/// be unique and can't be null.
+pub struct PrimaryConstraint {
+	unique: UniqueConstraint,
+}
+
+impl ConstraintImpl for PrimaryConstraint {
+	fn new(data: &ConstraintCreationData) -> Result<Self, ConstraintError> {
+		Ok( Self {
+			unique: UniqueConstraint::new(data)?,
+		} )
+	}
+
+	fn requires_unique_values(&self) -> bool {
+		true
+	}
+
+	fn apply(&mut self, value: CellValue) -> Result<Option<CellValue>, ConstraintError> {
+		if let CellValue::Null = value {
+			return Err( ConstraintError::NullNotAllowed( PRIMARY_CONSTRAINT_NAME.to_string() ) );
+		}
+
+		self.unique.apply(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_apply_should_accept_new_values() -> Result<(), ConstraintError> { // {{{
+		let mut sut = PrimaryConstraint::new( &ConstraintCreationData::default() )?;
+
+		assert_eq!( Some( CellValue::Int(1) ), sut.apply( CellValue::Int(1) )? );
+		assert_eq!( Some( CellValue::Int(2) ), sut.apply( CellValue::Int(2) )? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_apply_should_reject_duplicate_values() -> Result<(), ConstraintError> { // {{{
+		let mut sut = PrimaryConstraint::new( &ConstraintCreationData::default() )?;
+		sut.apply( CellValue::Int(1) )?;
+
+		assert_eq!( None, sut.apply( CellValue::Int(1) )? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_apply_should_return_error_for_null() -> Result<(), ConstraintError> { // {{{
+		let mut sut = PrimaryConstraint::new( &ConstraintCreationData::default() )?;
+
+		let result = sut.apply(CellValue::Null);
+
+		assert_eq!(
+			Err( ConstraintError::NullNotAllowed( PRIMARY_CONSTRAINT_NAME.to_string() ) ),
+			result,
+		);
+
+		Ok(())
+	} // }}}
+}
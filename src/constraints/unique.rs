@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use crate::{
+	constraint::{
+		ConstraintCreationData,
+		ConstraintError,
+		ConstraintImpl,
+	},
+	generator::CellValue,
+};
+
+pub const UNIQUE_CONSTRAINT_NAME: &str = "unique";
+
+/// Requires every value in a column to be unique. Null values are allowed
+/// multiple times.
+pub struct UniqueConstraint {
+	/// The values that are already used in the current column. Values are
+	/// stored in their displayed form, because [`CellValue`] can contain floats
+	/// which can't be hashed.
+	seen: HashSet<String>,
+}
+
+impl ConstraintImpl for UniqueConstraint {
+	fn new(_data: &ConstraintCreationData) -> Result<Self, ConstraintError> {
+		Ok( Self {
+			seen: HashSet::new(),
+		} )
+	}
+
+	fn requires_unique_values(&self) -> bool {
+		true
+	}
+
+	fn apply(&mut self, value: CellValue) -> Result<Option<CellValue>, ConstraintError> {
+		if let CellValue::Null = value {
+			return Ok( Some(value) );
+		}
+
+		if !self.seen.insert( value.to_string() ) {
+			return Ok(None);
+		}
+
+		Ok( Some(value) )
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_apply_should_reject_duplicate_values() -> Result<(), ConstraintError> { // {{{
+		let mut sut = UniqueConstraint::new( &ConstraintCreationData::default() )?;
+
+		assert_eq!( Some( CellValue::Int(1) ), sut.apply( CellValue::Int(1) )? );
+		assert_eq!( None, sut.apply( CellValue::Int(1) )? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_apply_should_accept_multiple_null_values() -> Result<(), ConstraintError> { // {{{
+		let mut sut = UniqueConstraint::new( &ConstraintCreationData::default() )?;
+
+		sut.apply(CellValue::Null)?;
+
+		assert_eq!( Some(CellValue::Null), sut.apply(CellValue::Null)? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_not_share_values_between_columns() -> Result<(), ConstraintError> { // {{{
+		let mut first = UniqueConstraint::new( &ConstraintCreationData::default() )?;
+		let mut sut = UniqueConstraint::new( &ConstraintCreationData::default() )?;
+
+		first.apply( CellValue::Int(1) )?;
+
+		assert_eq!( Some( CellValue::Int(1) ), sut.apply( CellValue::Int(1) )? );
+
+		Ok(())
+	} // }}}
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+
+/// The maximum amount of digits a [`Decimal`] can contain. Matches the largest
+/// decimal supported by most databases and still fits in an [`i128`].
+pub const MAX_PRECISION: u32 = 38;
+
+/// How to round a number that has more decimals than the scale allows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+	/// Round towards positive infinity
+	Up,
+	/// Round towards negative infinity
+	Down,
+}
+
+/// An exact decimal number, stored as an integer and the amount of digits
+/// after the decimal point. `12.50` is stored as `1250` with a scale of `2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Decimal {
+	value: i128,
+	scale: u32,
+}
+
+impl Decimal { // {{{
+	pub fn new(value: i128, scale: u32) -> Self {
+		Self { value, scale }
+	}
+
+	/// Parses a number like `-12.345` into a decimal with `scale` digits after
+	/// the decimal point. Digits that don't fit in the scale are rounded
+	/// according to `rounding`. Returns `None` when `text` isn't a number or
+	/// doesn't fit in a decimal.
+	pub fn parse(text: &str, scale: u32, rounding: Rounding) -> Option<Self> {
+		let (negative, text) = match text.strip_prefix('-') {
+			Some(text) => (true, text),
+			None => (false, text),
+		};
+		let (integer, fraction) = text.split_once('.')
+			.unwrap_or( (text, "") );
+
+		if integer.is_empty() || !integer.chars().chain( fraction.chars() ).all( |c| c.is_ascii_digit() ) {
+			return None;
+		}
+
+		let scale_length = scale as usize;
+		let (kept, dropped) = fraction.split_at( fraction.len().min(scale_length) );
+		let digits = format!("{}{}{}", integer, kept, "0".repeat( scale_length - kept.len() ));
+
+		let mut value: i128 = digits.parse().ok()?;
+
+		if negative {
+			value = -value;
+		}
+
+		if dropped.chars().any( |c| c != '0' ) {
+			// dropping digits rounds towards zero, so only round away from zero
+			// when that's the requested direction
+			match (rounding, negative) {
+				(Rounding::Up, false) => value = value.checked_add(1)?,
+				(Rounding::Down, true) => value = value.checked_sub(1)?,
+				_ => {},
+			}
+		}
+
+		Some( Self::new(value, scale) )
+	}
+
+	/// The largest value that fits in a decimal with `precision` digits, of
+	/// which `scale` are after the decimal point.
+	pub fn max(precision: u32, scale: u32) -> Self {
+		Self::new( 10_i128.pow(precision) - 1, scale )
+	}
+
+	pub fn value(&self) -> i128 {
+		self.value
+	}
+
+	pub fn scale(&self) -> u32 {
+		self.scale
+	}
+
+	/// Returns the amount of digits before the decimal point, ignoring leading
+	/// zeros.
+	pub fn integer_digits(&self) -> u32 {
+		let integer = self.value.unsigned_abs() / 10_u128.pow(self.scale);
+
+		match integer {
+			0 => 0,
+			integer => integer.ilog10() + 1,
+		}
+	}
+} // }}}
+
+impl fmt::Display for Decimal { // {{{
+	/// Writes the decimal with exactly `scale` digits after the decimal point,
+	/// never using scientific notation.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let sign = if self.value < 0 { "-" } else { "" };
+		let digits = self.value.unsigned_abs().to_string();
+		let scale = self.scale as usize;
+
+		if scale == 0 {
+			return write!(f, "{}{}", sign, digits);
+		}
+
+		// pad with zeros, so there is at least one digit before the point
+		let digits = format!( "{:0>width$}", digits, width = scale + 1 );
+		let (integer, fraction) = digits.split_at( digits.len() - scale );
+
+		write!(f, "{}{}.{}", sign, integer, fraction)
+	}
+} // }}}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_should_pad_the_fraction_to_the_scale() { // {{{
+		assert_eq!( Some( Decimal::new(1250, 2) ), Decimal::parse("12.5", 2, Rounding::Up) );
+		assert_eq!( Some( Decimal::new(1200, 2) ), Decimal::parse("12", 2, Rounding::Up) );
+	} // }}}
+
+	#[test]
+	fn test_parse_should_round_in_the_given_direction() { // {{{
+		assert_eq!( Some( Decimal::new(13, 1) ), Decimal::parse("1.25", 1, Rounding::Up) );
+		assert_eq!( Some( Decimal::new(12, 1) ), Decimal::parse("1.25", 1, Rounding::Down) );
+		assert_eq!( Some( Decimal::new(-12, 1) ), Decimal::parse("-1.25", 1, Rounding::Up) );
+		assert_eq!( Some( Decimal::new(-13, 1) ), Decimal::parse("-1.25", 1, Rounding::Down) );
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_none_for_invalid_numbers() { // {{{
+		assert_eq!( None, Decimal::parse("abc", 2, Rounding::Up) );
+		assert_eq!( None, Decimal::parse(".5", 2, Rounding::Up) );
+		assert_eq!( None, Decimal::parse("1e5", 2, Rounding::Up) );
+	} // }}}
+
+	#[test]
+	fn test_integer_digits_should_ignore_the_fraction() { // {{{
+		assert_eq!( 3, Decimal::new(-12345, 2).integer_digits() );
+		assert_eq!( 0, Decimal::new(5, 2).integer_digits() );
+	} // }}}
+
+	#[test]
+	fn test_to_string_should_write_exactly_scale_decimals() { // {{{
+		assert_eq!( "12.50", Decimal::new(1250, 2).to_string() );
+		assert_eq!( "-0.05", Decimal::new(-5, 2).to_string() );
+		assert_eq!( "42", Decimal::new(42, 0).to_string() );
+		assert_eq!( "99999999999999999999999999999999999999", Decimal::max(MAX_PRECISION, 0).to_string() );
+	} // }}}
+}
@@ -0,0 +1,471 @@
+use thiserror::Error;
+
+use crate::{
+	constraints::{
+		primary::PRIMARY_CONSTRAINT_NAME,
+		unique::UNIQUE_CONSTRAINT_NAME,
+	},
+	parser::config::{
+		Argument,
+		Column,
+		ColumnType,
+		Config,
+		Table,
+	},
+	provider::ProviderError,
+	providers::link::{ LinkTarget, LINK_PROVIDER_NAME },
+};
+
+#[derive(Debug, Error)]
+#[cfg_attr( test, derive(PartialEq) )]
+pub enum DependencyError {
+	/// Used when the link of column {0} is invalid: {1}
+	#[error("Invalid link in column '{0}': {1}")]
+	InvalidLink(String, ProviderError),
+
+	/// Used when column {0} links to a table {1} that doesn't exist
+	#[error("Column '{0}' links to unknown table '{1}'")]
+	UnknownTable(String, String),
+
+	/// Used when column {0} links to a column {1} that doesn't exist
+	#[error("Column '{0}' links to unknown column '{1}'")]
+	UnknownColumn(String, String),
+
+	/// Used when column {0} links to a column {1} that isn't a primary key or
+	/// unique
+	#[error("Column '{0}' links to column '{1}', but only primary key and unique columns can be linked to")]
+	TargetNotUnique(String, String),
+
+	/// Used when column {0} of type {2} links to column {1}, whose values of
+	/// type {3} don't fit in it
+	#[error("Column '{0}' of type '{2}' links to column '{1}' of type '{3}', whose values don't fit in it")]
+	IncompatibleTarget(String, String, ColumnType, ColumnType),
+
+	/// Used when items depend on each other. Contains the items in the cycle,
+	/// starting and ending with the same item
+	#[error("Cyclic link found: {}", .0.join(" -> "))]
+	Cycle(Vec<String>),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VisitState {
+	Unvisited,
+	Visiting,
+	Visited,
+}
+
+/// Returns the table a column links to, if the column uses a link provider.
+fn link_target(table: &Table, column: &Column) -> Result<Option<LinkTarget>, DependencyError> { // {{{
+	if column.provider.name != LINK_PROVIDER_NAME {
+		return Ok(None);
+	}
+
+	LinkTarget::parse( &column.provider.arguments, Some(&table.name) )
+		.map(Some)
+		.map_err( |e| DependencyError::InvalidLink(
+			format!("{}.{}", table.name, column.name),
+			e,
+		) )
+} // }}}
+
+/// Sorts the items so every item comes after the items it depends on. Items
+/// without dependencies between them keep their original order.
+///
+/// # Arguments
+///
+/// - `names` The names of the items, used when reporting a cycle
+/// - `dependencies` For every item, the indices of the items it depends on
+fn sort(names: &[String], dependencies: &[Vec<usize>]) -> Result<Vec<usize>, DependencyError> { // {{{
+	fn visit(
+		item: usize,
+		names: &[String],
+		dependencies: &[Vec<usize>],
+		states: &mut Vec<VisitState>,
+		path: &mut Vec<usize>,
+		order: &mut Vec<usize>,
+	) -> Result<(), DependencyError> {
+		match states[item] {
+			VisitState::Visited => return Ok(()),
+			VisitState::Visiting => {
+				let start = path.iter()
+					.position( |i| *i == item )
+					.expect("an item that is being visited should be in the path");
+				let cycle = path[start..].iter()
+					.chain( [item].iter() )
+					.map( |i| names[*i].clone() )
+					.collect();
+
+				return Err( DependencyError::Cycle(cycle) );
+			},
+			VisitState::Unvisited => {},
+		}
+
+		states[item] = VisitState::Visiting;
+		path.push(item);
+
+		for dependency in &dependencies[item] {
+			visit(*dependency, names, dependencies, states, path, order)?;
+		}
+
+		path.pop();
+		states[item] = VisitState::Visited;
+		order.push(item);
+
+		Ok(())
+	}
+
+	let mut states = vec![ VisitState::Unvisited; names.len() ];
+	let mut path = Vec::with_capacity( names.len() );
+	let mut order = Vec::with_capacity( names.len() );
+
+	for item in 0..names.len() {
+		visit(item, names, dependencies, &mut states, &mut path, &mut order)?;
+	}
+
+	Ok(order)
+} // }}}
+
+/// Checks if the column `column` of `table` links to a column that exists and
+/// can be linked to. Returns the target of the link, or `None` when the
+/// column doesn't use a link provider.
+pub fn check_link(config: &Config, table: &Table, column: &Column) -> Result<Option<LinkTarget>, DependencyError> { // {{{
+	let Some(target) = link_target(table, column)? else {
+		return Ok(None);
+	};
+
+	let column_name = format!("{}.{}", table.name, column.name);
+	let target_table = config.tables.iter()
+		.find( |t| t.name == target.table )
+		.ok_or_else( || DependencyError::UnknownTable(
+			column_name.clone(),
+			target.table.clone(),
+		) )?;
+
+	let target_column = target_table.columns.iter()
+		.find( |c| c.name == target.column )
+		.ok_or_else( || DependencyError::UnknownColumn(
+			column_name.clone(),
+			target.to_string(),
+		) )?;
+
+	let target_is_unique = target_column.constraints.iter()
+		.any( |c| c.name == PRIMARY_CONSTRAINT_NAME || c.name == UNIQUE_CONSTRAINT_NAME );
+
+	if !target_is_unique {
+		return Err( DependencyError::TargetNotUnique(
+			column_name,
+			target.to_string(),
+		) );
+	}
+
+	// the values are copied, so they have to fit like the values of any
+	// other provider
+	if !column.kind.accepts(&target_column.kind) {
+		return Err( DependencyError::IncompatibleTarget(
+			column_name,
+			target.to_string(),
+			column.kind.clone(),
+			target_column.kind.clone(),
+		) );
+	}
+
+	Ok( Some(target) )
+} // }}}
+
+/// Rewrites the argument of every link provider to the `"TableName.column_name"`
+/// form and checks if the linked columns exist.
+pub fn resolve_links(config: &mut Config) -> Result<(), DependencyError> { // {{{
+	let mut resolved = Vec::new();
+
+	for (table_index, table) in config.tables.iter().enumerate() {
+		for (column_index, column) in table.columns.iter().enumerate() {
+			if let Some(target) = check_link(config, table, column)? {
+				resolved.push( (table_index, column_index, target) );
+			}
+		}
+	}
+
+	for (table_index, column_index, target) in resolved {
+		config.tables[table_index].columns[column_index].provider.arguments[0]
+			= Argument::String( target.to_string() );
+	}
+
+	Ok(())
+} // }}}
+
+/// Returns the indices of the tables in `config` in the order they should be
+/// generated in, so every linked table is generated before the tables linking
+/// to it.
+pub fn table_order(config: &Config) -> Result<Vec<usize>, DependencyError> { // {{{
+	let names: Vec<String> = config.tables.iter()
+		.map( |t| t.name.clone() )
+		.collect();
+	let mut dependencies = vec![ Vec::new(); names.len() ];
+
+	for (table_index, table) in config.tables.iter().enumerate() {
+		for column in &table.columns {
+			let Some(target) = link_target(table, column)? else {
+				continue;
+			};
+
+			// links within the same table are handled by `column_order`
+			if target.table == table.name {
+				continue;
+			}
+
+			if let Some(target_index) = names.iter().position( |n| *n == target.table ) {
+				dependencies[table_index].push(target_index);
+			}
+		}
+	}
+
+	sort(&names, &dependencies)
+} // }}}
+
+/// Returns the indices of the columns in `table` in the order they should be
+/// generated in, so every column is generated after the columns in the same
+/// table it links to.
+pub fn column_order(table: &Table) -> Result<Vec<usize>, DependencyError> { // {{{
+	let names: Vec<String> = table.columns.iter()
+		.map( |c| format!("{}.{}", table.name, c.name) )
+		.collect();
+	let mut dependencies = vec![ Vec::new(); names.len() ];
+
+	for (column_index, column) in table.columns.iter().enumerate() {
+		let Some(target) = link_target(table, column)? else {
+			continue;
+		};
+
+		if target.table != table.name {
+			continue;
+		}
+
+		if let Some(target_index) = table.columns.iter().position( |c| c.name == target.column ) {
+			dependencies[column_index].push(target_index);
+		}
+	}
+
+	sort(&names, &dependencies)
+} // }}}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::parser::config::{
+		Constraint,
+		Provider,
+	};
+
+	fn column(name: &str, provider: &str, arguments: Vec<Argument>) -> Column { // {{{
+		Column::new(
+			name.to_string(),
+			ColumnType::UnsignedInt,
+			vec![],
+			Provider::new( provider.to_string(), arguments, vec![] ),
+		)
+	} // }}}
+
+	fn primary(name: &str) -> Column { // {{{
+		let mut column = column(name, "row", vec![]);
+
+		column.constraints.push( Constraint::new(
+			PRIMARY_CONSTRAINT_NAME.to_string(),
+			vec![],
+			vec![],
+		) );
+
+		column
+	} // }}}
+
+	fn link(name: &str, target: &str) -> Column { // {{{
+		column( name, LINK_PROVIDER_NAME, vec![ Argument::String( target.to_string() ) ] )
+	} // }}}
+
+	fn config(tables: Vec<Table>) -> Config { // {{{
+		let mut config = Config::new();
+
+		for table in tables {
+			config.add_table(table);
+		}
+
+		config
+	} // }}}
+
+	#[test]
+	fn test_resolve_links_should_add_the_current_table_to_column_links() -> Result<(), DependencyError> { // {{{
+		let mut config = config( vec![
+			Table::new( "A".to_string(), None, vec![
+				primary("id"),
+				link("copy", "id"),
+			] ),
+		] );
+
+		resolve_links(&mut config)?;
+
+		assert_eq!(
+			vec![ Argument::String( "A.id".to_string() ) ],
+			config.tables[0].columns[1].provider.arguments,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_links_should_return_error_for_unknown_tables() -> Result<(), DependencyError> { // {{{
+		let mut config = config( vec![
+			Table::new( "A".to_string(), None, vec![ link("id", "B.id") ] ),
+		] );
+
+		let result = resolve_links(&mut config);
+
+		assert_eq!(
+			Err( DependencyError::UnknownTable( "A.id".to_string(), "B".to_string() ) ),
+			result,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_links_should_return_error_for_unknown_columns() -> Result<(), DependencyError> { // {{{
+		let mut config = config( vec![
+			Table::new( "A".to_string(), None, vec![ link("id", "B.unknown") ] ),
+			Table::new( "B".to_string(), None, vec![ column("id", "row", vec![]) ] ),
+		] );
+
+		let result = resolve_links(&mut config);
+
+		assert_eq!(
+			Err( DependencyError::UnknownColumn( "A.id".to_string(), "B.unknown".to_string() ) ),
+			result,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_links_should_return_error_when_the_column_is_no_primary_key() -> Result<(), DependencyError> { // {{{
+		let mut config = config( vec![
+			Table::new( "A".to_string(), None, vec![ link("b_id", "B.id") ] ),
+			Table::new( "B".to_string(), None, vec![ column("id", "row", vec![]) ] ),
+		] );
+
+		let result = resolve_links(&mut config);
+
+		assert_eq!(
+			Err( DependencyError::TargetNotUnique( "A.b_id".to_string(), "B.id".to_string() ) ),
+			result,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_links_should_return_error_when_the_target_does_not_fit() -> Result<(), DependencyError> { // {{{
+		let mut copy = link("copy", "id");
+		copy.kind = ColumnType::String(10);
+
+		let mut config = config( vec![
+			Table::new( "A".to_string(), None, vec![ primary("id"), copy ] ),
+		] );
+
+		let result = resolve_links(&mut config);
+
+		assert_eq!(
+			Err( DependencyError::IncompatibleTarget(
+				"A.copy".to_string(),
+				"A.id".to_string(),
+				ColumnType::String(10),
+				ColumnType::UnsignedInt,
+			) ),
+			result,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_table_order_should_generate_linked_tables_first() -> Result<(), DependencyError> { // {{{
+		let config = config( vec![
+			Table::new( "A".to_string(), None, vec![ link("b_id", "B.id") ] ),
+			Table::new( "B".to_string(), None, vec![ link("c_id", "C.id") ] ),
+			Table::new( "C".to_string(), None, vec![ column("id", "row", vec![]) ] ),
+		] );
+
+		let result = table_order(&config)?;
+
+		assert_eq!( vec![2, 1, 0], result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_table_order_should_keep_the_order_of_independent_tables() -> Result<(), DependencyError> { // {{{
+		let config = config( vec![
+			Table::new( "A".to_string(), None, vec![ column("id", "row", vec![]) ] ),
+			Table::new( "B".to_string(), None, vec![ column("id", "row", vec![]) ] ),
+		] );
+
+		let result = table_order(&config)?;
+
+		assert_eq!( vec![0, 1], result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_table_order_should_return_error_for_cycles() -> Result<(), DependencyError> { // {{{
+		let config = config( vec![
+			Table::new( "A".to_string(), None, vec![ link("b_id", "B.id") ] ),
+			Table::new( "B".to_string(), None, vec![ link("id", "A.b_id") ] ),
+		] );
+
+		let result = table_order(&config);
+
+		assert_eq!(
+			Err( DependencyError::Cycle( vec![
+				"A".to_string(),
+				"B".to_string(),
+				"A".to_string(),
+			] ) ),
+			result,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_column_order_should_generate_linked_columns_first() -> Result<(), DependencyError> { // {{{
+		let table = Table::new( "A".to_string(), None, vec![
+			link("copy", "id"),
+			column("id", "row", vec![]),
+		] );
+
+		let result = column_order(&table)?;
+
+		assert_eq!( vec![1, 0], result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_column_order_should_return_error_for_cycles() -> Result<(), DependencyError> { // {{{
+		let table = Table::new( "A".to_string(), None, vec![
+			link("first", "second"),
+			link("second", "first"),
+		] );
+
+		let result = column_order(&table);
+
+		assert_eq!(
+			Err( DependencyError::Cycle( vec![
+				"A.first".to_string(),
+				"A.second".to_string(),
+				"A.first".to_string(),
+			] ) ),
+			result,
+		);
+
+		Ok(())
+	} // }}}
+}
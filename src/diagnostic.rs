@@ -0,0 +1,203 @@
+use std::{
+	fmt,
+	path::PathBuf,
+	sync::Arc,
+};
+
+/// A file the config is read from. Kept around, so errors can show the line
+/// they were found at.
+#[derive(Debug)]
+pub struct Source {
+	pub path: PathBuf,
+	pub content: String,
+}
+
+impl Source { // {{{
+	pub fn new(path: PathBuf, content: String) -> Self {
+		Self { path, content }
+	}
+} // }}}
+
+/// A part of a source file, usually the text an item of the config was parsed
+/// from.
+#[derive(Clone)]
+pub struct Location {
+	source: Arc<Source>,
+	/// The byte offset the location starts at
+	start: usize,
+	/// The byte offset the location ends at, exclusive
+	end: usize,
+}
+
+impl Location { // {{{
+	pub fn new(source: Arc<Source>, start: usize, end: usize) -> Self {
+		Self { source, start, end }
+	}
+
+	pub fn from_span(source: &Arc<Source>, span: pest::Span) -> Self {
+		Self::new( source.clone(), span.start(), span.end() )
+	}
+
+	pub fn path(&self) -> &PathBuf {
+		&self.source.path
+	}
+
+	/// Returns the line and column the location starts at, both starting at 1.
+	/// Columns are counted in characters.
+	pub fn line_column(&self) -> (usize, usize) {
+		let before = &self.source.content[..self.start];
+		let line = before.matches('\n').count() + 1;
+		let column = before[ self.line_start().. ].chars().count() + 1;
+
+		(line, column)
+	}
+
+	/// Returns the byte offset of the start of the line the location starts at
+	fn line_start(&self) -> usize {
+		self.source.content[..self.start]
+			.rfind('\n')
+			.map_or(0, |i| i + 1)
+	}
+
+	/// Returns the line the location starts at, without the newline
+	fn line_text(&self) -> &str {
+		let rest = &self.source.content[ self.line_start().. ];
+
+		rest.lines()
+			.next()
+			.unwrap_or("")
+	}
+} // }}}
+
+impl fmt::Display for Location { // {{{
+	/// Writes the location as `path:line:column`
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (line, column) = self.line_column();
+
+		write!(f, "{}:{}:{}", self.path().display(), line, column)
+	}
+} // }}}
+
+impl fmt::Debug for Location { // {{{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+} // }}}
+
+impl PartialEq for Location { // {{{
+	fn eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.source, &other.source)
+			&& self.start == other.start
+			&& self.end == other.end
+	}
+} // }}}
+
+/// An error in the config, with the location it was found at when it's known
+#[derive(Debug)]
+pub struct Diagnostic {
+	pub error: anyhow::Error,
+	pub location: Option<Location>,
+}
+
+impl Diagnostic { // {{{
+	pub fn new(error: impl Into<anyhow::Error>, location: Option<Location>) -> Self {
+		Self {
+			error: error.into(),
+			location,
+		}
+	}
+} // }}}
+
+impl fmt::Display for Diagnostic { // {{{
+	/// Writes the error like rustc does, with the line it was found at and the
+	/// location marked below it:
+	///
+	/// ```text
+	/// error: Unknown parameter 'mx' for 'Account.id #number', expected one of: min, max
+	///  --> file.mock:2:12
+	///   |
+	/// 2 |     id int #number(mx = 5)
+	///   |            ^^^^^^^^^^^^^^^
+	/// ```
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "error: {}", self.error)?;
+
+		let Some(location) = &self.location else {
+			return Ok(());
+		};
+
+		let (line, column) = location.line_column();
+		let text = location.line_text();
+		let gutter = " ".repeat( line.to_string().len() );
+
+		// keep the tabs, so the markers line up with the text above them
+		let padding: String = text.chars()
+			.take(column - 1)
+			.map( |c| if c == '\t' { '\t' } else { ' ' } )
+			.collect();
+		// locations spanning multiple lines are only marked on the first line
+		let length = location.source.content[location.start..location.end]
+			.lines()
+			.next()
+			.map_or(0, |marked| marked.chars().count())
+			.max(1);
+
+		writeln!(f, "{}--> {}", gutter, location)?;
+		writeln!(f, "{} |", gutter)?;
+		writeln!(f, "{} | {}", line, text)?;
+		writeln!(f, "{} | {}{}", gutter, padding, "^".repeat(length))
+	}
+} // }}}
+
+/// Every error found in a config, so they can be reported at once
+#[derive(Debug)]
+pub struct Diagnostics(pub Vec<Diagnostic>);
+
+impl fmt::Display for Diagnostics { // {{{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for diagnostic in &self.0 {
+			writeln!(f, "{}", diagnostic)?;
+		}
+
+		match self.0.len() {
+			1 => write!(f, "error: aborting due to 1 previous error"),
+			count => write!(f, "error: aborting due to {} previous errors", count),
+		}
+	}
+} // }}}
+
+impl std::error::Error for Diagnostics {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn source(content: &str) -> Arc<Source> { // {{{
+		Arc::new( Source::new( PathBuf::from("test.mock"), content.to_string() ) )
+	} // }}}
+
+	#[test]
+	fn test_line_column_should_count_lines_and_characters() { // {{{
+		let sut = Location::new( source("table A {\n\tid uint #row()\n}"), 19, 25 );
+
+		assert_eq!( (2, 10), sut.line_column() );
+	} // }}}
+
+	#[test]
+	fn test_to_string_should_write_the_path_line_and_column() { // {{{
+		let sut = Location::new( source("table A {\n\tid uint #row()\n}"), 19, 25 );
+
+		assert_eq!( "test.mock:2:10", sut.to_string() );
+	} // }}}
+
+	#[test]
+	fn test_to_string_should_mark_the_location_below_its_line() { // {{{
+		let location = Location::new( source("table A {\n\tid uint #row()\n}"), 19, 25 );
+		let sut = Diagnostic::new( anyhow::anyhow!("something is wrong"), Some(location) );
+
+		assert_eq!(
+			"error: something is wrong\n --> test.mock:2:10\n  |\n2 | \tid uint #row()\n  | \t        ^^^^^^\n",
+			sut.to_string(),
+		);
+	} // }}}
+}
@@ -0,0 +1,238 @@
+use rand::RngCore;
+use rand_distr::{
+	Distribution as _,
+	Exp,
+	Normal,
+	Poisson,
+	Zipf,
+};
+
+use crate::{
+	parameter::{ Parameter, ParameterKind },
+	parser::config::Argument,
+	provider::ProviderError,
+};
+
+// distribution names {{{
+pub const DISTRIBUTION_UNIFORM: &str = "uniform";
+pub const DISTRIBUTION_NORMAL: &str = "normal";
+pub const DISTRIBUTION_EXPONENTIAL: &str = "exponential";
+pub const DISTRIBUTION_ZIPF: &str = "zipf";
+pub const DISTRIBUTION_POISSON: &str = "poisson";
+// }}}
+
+/// The distribution numeric providers draw their values from. Every
+/// distribution except the uniform one is sampled as a float, which the
+/// provider rounds and clamps to its minimum and maximum.
+#[derive(Debug)]
+pub enum Distribution {
+	/// Every value between the minimum and maximum is equally likely
+	Uniform,
+	/// `normal, mean, std_dev`
+	Normal( Normal<f64> ),
+	/// `exponential, mean`
+	Exponential( Exp<f64> ),
+	/// `zipf, exponent`. The minimum is the most common value, the value after
+	/// it the second most common and so on
+	Zipf {
+		zipf: Zipf<f64>,
+		min: f64,
+	},
+	/// `poisson, mean`
+	Poisson( Poisson<f64> ),
+}
+
+impl Distribution { // {{{
+	/// Returns the parameters selecting the distribution. Numeric providers
+	/// add them after their own parameters.
+	pub fn parameters() -> Vec<Parameter> { // {{{
+		vec![
+			Parameter::new("distribution", ParameterKind::String)
+				.with_default( Argument::String( DISTRIBUTION_UNIFORM.to_string() ) ),
+			Parameter::new("distribution_arguments", ParameterKind::Number).variadic(),
+		]
+	} // }}}
+
+	/// Creates the distribution named by `name`, with `arguments` as its
+	/// parameters. Returns an error when the distribution is unknown, when its
+	/// parameters are missing or invalid, or when `max` is below `min`, as
+	/// sampled values couldn't be clamped to the range.
+	///
+	/// # Arguments
+	///
+	/// - `name` The name of the distribution, the uniform one when `None`
+	/// - `arguments` The arguments given after the name of the distribution
+	/// - `min` The minimum of the provider, used by distributions over ranks
+	/// - `max` The maximum of the provider, used by distributions over ranks
+	pub fn new(name: Option<&Argument>, arguments: &[Argument], min: f64, max: f64) -> Result<Self, ProviderError> { // {{{
+		let name = match name {
+			Some( Argument::String(name) ) => name.as_str(),
+			Some(argument) => return Err( ProviderError::UnexpectedArgument(
+				argument.to_string(),
+				"the name of a distribution".to_string(),
+			) ),
+			None => DISTRIBUTION_UNIFORM,
+		};
+		if max < min {
+			return Err( ProviderError::UnexpectedArgument(
+				max.to_string(),
+				format!("a number of at least {}", min),
+			) );
+		}
+
+		let values = arguments.iter()
+			.map( |argument| match argument {
+				Argument::Int(value) => Ok(*value as f64),
+				Argument::Float(value) => Ok(*value),
+				argument => Err( ProviderError::UnexpectedArgument(
+					argument.to_string(),
+					"int or float".to_string(),
+				) ),
+			} )
+			.collect::< Result<Vec<f64>, ProviderError> >()?;
+		let invalid = |reason: &str| ProviderError::InvalidDistribution( format!(
+			"'{}' {}",
+			name,
+			reason,
+		) );
+
+		match (name, values.as_slice()) {
+			(DISTRIBUTION_UNIFORM, []) => Ok(Self::Uniform),
+
+			(DISTRIBUTION_NORMAL, [mean, std_dev]) => match mean.is_finite() && std_dev.is_finite() && *std_dev > 0.0 {
+				true => Ok( Self::Normal( Normal::new(*mean, *std_dev).map_err( |e| invalid( &e.to_string() ) )? ) ),
+				false => Err( invalid("needs a finite mean and a standard deviation above 0") ),
+			},
+
+			(DISTRIBUTION_EXPONENTIAL, [mean]) => match mean.is_finite() && *mean > 0.0 {
+				true => Ok( Self::Exponential( Exp::new(1.0 / mean).map_err( |e| invalid( &e.to_string() ) )? ) ),
+				false => Err( invalid("needs a finite mean above 0") ),
+			},
+
+			(DISTRIBUTION_ZIPF, [exponent]) => match exponent.is_finite() && *exponent > 0.0 {
+				true => {
+					// the amount of ranks between the minimum and maximum
+					let count = (max - min + 1.0).clamp(1.0, u64::MAX as f64) as u64;
+
+					Ok( Self::Zipf {
+						zipf: Zipf::new(count, *exponent).map_err( |e| invalid( &e.to_string() ) )?,
+						min,
+					} )
+				},
+				false => Err( invalid("needs a finite exponent above 0") ),
+			},
+
+			(DISTRIBUTION_POISSON, [mean]) => match mean.is_finite() && *mean > 0.0 {
+				true => Ok( Self::Poisson( Poisson::new(*mean).map_err( |e| invalid( &e.to_string() ) )? ) ),
+				false => Err( invalid("needs a finite mean above 0") ),
+			},
+
+			(DISTRIBUTION_UNIFORM, _) => Err( invalid("doesn't accept any arguments") ),
+			(DISTRIBUTION_NORMAL, _) => Err( invalid("needs 2 arguments: the mean and the standard deviation") ),
+			(DISTRIBUTION_EXPONENTIAL | DISTRIBUTION_POISSON, _) => Err( invalid("needs 1 argument: the mean") ),
+			(DISTRIBUTION_ZIPF, _) => Err( invalid("needs 1 argument: the exponent") ),
+
+			(name, _) => Err( ProviderError::InvalidDistribution( format!(
+				"unknown distribution '{}', expected one of: {}",
+				name,
+				[
+					DISTRIBUTION_UNIFORM,
+					DISTRIBUTION_NORMAL,
+					DISTRIBUTION_EXPONENTIAL,
+					DISTRIBUTION_ZIPF,
+					DISTRIBUTION_POISSON,
+				].join(", "),
+			) ) ),
+		}
+	} // }}}
+
+	/// Returns a value drawn from the distribution, which isn't rounded or
+	/// clamped yet. Returns `None` for the uniform distribution, which
+	/// providers sample within their range themselves.
+	pub fn sample(&self, rng: &mut dyn RngCore) -> Option<f64> { // {{{
+		match self {
+			Self::Uniform => None,
+			Self::Normal(normal) => Some( normal.sample(rng) ),
+			Self::Exponential(exponential) => Some( exponential.sample(rng) ),
+			// ranks start at 1
+			Self::Zipf { zipf, min } => Some( min + zipf.sample(rng) - 1.0 ),
+			Self::Poisson(poisson) => Some( poisson.sample(rng) ),
+		}
+	} // }}}
+} // }}}
+
+#[cfg(test)]
+mod tests {
+	use rand::{ rngs::StdRng, SeedableRng };
+
+	use super::*;
+
+	/// Every distribution with valid arguments
+	const DISTRIBUTIONS: [(&str, &[f64]); 5] = [
+		(DISTRIBUTION_UNIFORM, &[]),
+		(DISTRIBUTION_NORMAL, &[50.0, 10.0]),
+		(DISTRIBUTION_EXPONENTIAL, &[20.0]),
+		(DISTRIBUTION_ZIPF, &[1.5]),
+		(DISTRIBUTION_POISSON, &[20.0]),
+	];
+
+	fn distribution(name: &str, arguments: &[f64]) -> Result<Distribution, ProviderError> { // {{{
+		let arguments: Vec<Argument> = arguments.iter()
+			.map( |value| Argument::Float(*value) )
+			.collect();
+
+		Distribution::new( Some( &Argument::String( name.to_string() ) ), &arguments, 1.0, 100.0 )
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_for_invalid_parameters() { // {{{
+		assert!( matches!( distribution("normal", &[50.0, 0.0]), Err( ProviderError::InvalidDistribution(_) ) ) );
+		assert!( matches!( distribution("normal", &[50.0]), Err( ProviderError::InvalidDistribution(_) ) ) );
+		assert!( matches!( distribution("exponential", &[-1.0]), Err( ProviderError::InvalidDistribution(_) ) ) );
+		assert!( matches!( distribution("poisson", &[f64::INFINITY]), Err( ProviderError::InvalidDistribution(_) ) ) );
+		assert!( matches!( distribution("zipf", &[]), Err( ProviderError::InvalidDistribution(_) ) ) );
+		assert!( matches!( distribution("uniform", &[1.0]), Err( ProviderError::InvalidDistribution(_) ) ) );
+		assert!( matches!( distribution("gauss", &[]), Err( ProviderError::InvalidDistribution(_) ) ) );
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_for_an_inverted_range() { // {{{
+		for (name, arguments) in DISTRIBUTIONS {
+			let arguments: Vec<Argument> = arguments.iter()
+				.map( |value| Argument::Float(*value) )
+				.collect();
+
+			let result = Distribution::new( Some( &Argument::String( name.to_string() ) ), &arguments, 100.0, 1.0 );
+
+			assert!( matches!( result, Err( ProviderError::UnexpectedArgument(_, _) ) ), "{}", name );
+		}
+	} // }}}
+
+	#[test]
+	fn test_sample_should_make_the_minimum_the_most_common_zipf_value() -> Result<(), ProviderError> { // {{{
+		let sut = distribution("zipf", &[1.5])?;
+		let mut rng = StdRng::seed_from_u64(42);
+		let mut counts = [0; 100];
+
+		for _ in 0..10_000 {
+			let value = sut.sample(&mut rng).unwrap();
+
+			assert!( (1.0..=100.0).contains(&value) );
+
+			counts[ value as usize - 1 ] += 1;
+		}
+
+		assert!( counts[0] > counts[1] && counts[1] > counts[9], "{:?}", &counts[..10] );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_sample_should_return_none_for_the_uniform_distribution() -> Result<(), ProviderError> { // {{{
+		let sut = Distribution::new(None, &[], 1.0, 100.0)?;
+
+		assert_eq!( None, sut.sample( &mut StdRng::seed_from_u64(42) ) );
+
+		Ok(())
+	} // }}}
+}
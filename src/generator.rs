@@ -1,12 +1,32 @@
-use std::fs::File;
+use chrono::{
+	NaiveDate,
+	NaiveDateTime,
+	NaiveTime,
+};
+use std::{
+	collections::HashMap,
+	fs::File,
+};
 use thiserror::Error;
 
-use crate::parser::config::{
-	self as Types,
-	Argument,
-	ColumnType,
+use crate::{
+	decimal::Decimal,
+	parser::config::{
+		self as Types,
+		Argument,
+		ColumnType,
+	},
 };
 
+// date and time formats {{{
+/// The format dates are written in, following ISO 8601
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+/// The format times are written in, following ISO 8601
+pub const TIME_FORMAT: &str = "%H:%M:%S";
+/// The format dates with a time are written in, following ISO 8601
+pub const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+// }}}
+
 #[derive(Clone, Debug)]
 #[cfg_attr( test, derive(PartialEq) )]
 pub enum CellValue {
@@ -15,19 +35,48 @@ pub enum CellValue {
 	Float(f64),
 	String(String),
 	Boolean(bool),
+	Decimal(Decimal),
+	Date(NaiveDate),
+	Time(NaiveTime),
+	DateTime(NaiveDateTime),
+	Null,
 }
 
 impl From<&Argument> for CellValue { // {{{
 	fn from(arg: &Argument) -> Self {
 		match arg {
-			Argument::Int(value) => Self::Int( value.clone() ),
-			Argument::Float(value) => Self::Float( value.clone() ),
+			Argument::Int(value) => Self::Int(*value),
+			Argument::Float(value) => Self::Float(*value),
 			Argument::String(value) => Self::String( value.clone() ),
-			Argument::Boolean(value) => Self::Boolean( value.clone() ),
+			Argument::Boolean(value) => Self::Boolean(*value),
 		}
 	}
 } // }}}
 
+impl CellValue { // {{{
+	/// Returns the smallest column type the value fits in, or `None` for
+	/// `null`, which fits in every column.
+	pub fn kind(&self) -> Option<ColumnType> {
+		let kind = match self {
+			Self::Int(_) => ColumnType::Int,
+			Self::UnsignedInt(_) => ColumnType::UnsignedInt,
+			Self::Float(_) => ColumnType::Float,
+			Self::Boolean(_) => ColumnType::Boolean,
+			Self::String(value) => ColumnType::String( value.chars().count() ),
+			Self::Decimal(value) => ColumnType::Decimal(
+				value.integer_digits() + value.scale(),
+				value.scale(),
+			),
+			Self::Date(_) => ColumnType::Date,
+			Self::Time(_) => ColumnType::Time,
+			Self::DateTime(_) => ColumnType::DateTime,
+			Self::Null => return None,
+		};
+
+		Some(kind)
+	}
+} // }}}
+
 impl std::fmt::Display for CellValue { // {{{
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 		macro_rules! write_type {
@@ -42,6 +91,11 @@ impl std::fmt::Display for CellValue { // {{{
 			Self::Float(value) => write_type!(Types::KEY_COLUMN_TYPE_FLOAT, value),
 			Self::Boolean(value) => write_type!(Types::KEY_COLUMN_TYPE_BOOLEAN, value),
 			Self::String(value) => write_type!(Types::KEY_COLUMN_TYPE_STRING, value),
+			Self::Decimal(value) => write_type!(Types::KEY_COLUMN_TYPE_DECIMAL, value),
+			Self::Date(value) => write_type!( Types::KEY_COLUMN_TYPE_DATE, value.format(DATE_FORMAT) ),
+			Self::Time(value) => write_type!( Types::KEY_COLUMN_TYPE_TIME, value.format(TIME_FORMAT) ),
+			Self::DateTime(value) => write_type!( Types::KEY_COLUMN_TYPE_DATE_TIME, value.format(DATE_TIME_FORMAT) ),
+			Self::Null => write!(f, "null"),
 		}
 	}
 } // }}}
@@ -51,10 +105,16 @@ pub struct ColumnData {
 	pub name: String,
 	pub r#type: ColumnType,
 	pub data: Vec<CellValue>,
+	/// The doc comment of the column, written by generators whose output
+	/// supports comments
+	pub comment: Option<String>,
 }
 
 pub type GeneratorData = Vec<ColumnData>;
 
+/// The generated data of every table, indexed by the name of the table
+pub type MockData = HashMap<String, GeneratorData>;
+
 #[derive(Debug, Error)]
 pub enum GeneratorError { // {{{
 	// TODO: Add generator name?
@@ -66,15 +126,60 @@ pub enum GeneratorError { // {{{
 	/// Used when something goes wrong while writing to the output file.
 	#[error("something went wrong while writing data to the output file: {0}")]
 	Write(String),
+
+	/// Used when the options given to a generator can't be used, {0}
+	/// describes why
+	#[error("invalid generator options: {0}")]
+	InvalidOptions(String),
 } // }}}
 
-pub struct GeneratorCreationData {}
+pub struct GeneratorCreationData {
+	/// The character used to separate fields in csv output
+	pub csv_delimiter: char,
+	/// The character used to quote fields in csv output
+	pub csv_quote: char,
+	/// Whether json output should be written without indentation
+	pub json_compact: bool,
+	/// Whether json output should contain one object per line instead of a
+	/// single array
+	pub json_newline_delimited: bool,
+	/// The name of the root element in xml output
+	pub xml_root: String,
+	/// The name of the element wrapping a single row in xml output
+	pub xml_row: String,
+	/// Whether columns should be written as attributes in xml output
+	pub xml_attributes: bool,
+	/// Whether tsql output should add a check constraint to every column of an
+	/// enum type, restricting it to the variants of the enum
+	pub tsql_enum_checks: bool,
+}
+
+impl Default for GeneratorCreationData { // {{{
+	fn default() -> Self {
+		Self {
+			csv_delimiter: ',',
+			csv_quote: '"',
+			json_compact: false,
+			json_newline_delimited: false,
+			xml_root: "data".to_string(),
+			xml_row: "entry".to_string(),
+			xml_attributes: false,
+			tsql_enum_checks: false,
+		}
+	}
+} // }}}
 
 pub trait GeneratorImpl { // {{{
-	fn new() -> Result<Self, GeneratorError>
+	fn new(data: &GeneratorCreationData) -> Result<Self, GeneratorError>
 		where Self: Sized;
 
-	fn init(&mut self, table_name: String, row_count: usize, output_file: File) -> Result<(), GeneratorError>;
+	fn init(
+		&mut self,
+		table_name: String,
+		table_comment: Option<String>,
+		row_count: usize,
+		output_file: File,
+	) -> Result<(), GeneratorError>;
 
 	fn format_cell_value(&mut self, value: &CellValue) -> Result<String, GeneratorError>;
 
@@ -0,0 +1,309 @@
+use log::debug;
+use std::{
+	fs::File,
+	io::Write,
+};
+
+use crate::generator::{
+	CellValue,
+	ColumnData,
+	GeneratorCreationData,
+	GeneratorData,
+	GeneratorError,
+	GeneratorImpl,
+	DATE_FORMAT,
+	DATE_TIME_FORMAT,
+	TIME_FORMAT,
+};
+
+pub struct CsvGenerator {
+	row_count: usize,
+	output_file: Option<File>,
+	delimiter: char,
+	quote: char,
+	initialized: bool,
+}
+
+impl CsvGenerator {
+	fn write_to_output(&mut self, data: String) -> Result<(), GeneratorError> { // {{{
+		self.output_file.as_ref()
+			.unwrap()
+			.write( data.as_bytes() )
+			.map_err( |e| GeneratorError::Write( e.to_string() ) )?;
+
+		Ok(())
+	} // }}}
+
+	/// Quotes `field` according to RFC 4180 when it contains the delimiter,
+	/// the quote character or a line break. Quote characters inside the field
+	/// are escaped by doubling them.
+	fn escape_field(&self, field: &str) -> String { // {{{
+		let needs_quoting = field.chars()
+			.any( |c| c == self.delimiter || c == self.quote || c == '\n' || c == '\r' );
+
+		if !needs_quoting {
+			return field.to_string();
+		}
+
+		let quote = self.quote.to_string();
+		let escaped = field.replace( &quote, &quote.repeat(2) );
+
+		format!("{}{}{}", quote, escaped, quote)
+	} // }}}
+
+	fn generate_header(&mut self, columns: &[ColumnData]) -> Result<(), GeneratorError> { // {{{
+		let header = columns.iter()
+			.map( |c| self.escape_field(&c.name) )
+			.collect::< Vec<String> >()
+			.join( &self.delimiter.to_string() );
+
+		self.write_to_output( format!("{}\r\n", header) )?;
+
+		Ok(())
+	} // }}}
+
+	fn generate_row(&mut self, rows: &[&CellValue]) -> Result<(), GeneratorError> { // {{{
+		debug!("writing data: {:?}", rows);
+
+		let mut fields = Vec::with_capacity( rows.len() );
+
+		for row in rows {
+			fields.push( self.format_cell_value(row)? );
+		}
+
+		let line = fields.join( &self.delimiter.to_string() );
+
+		self.write_to_output( format!("{}\r\n", line) )?;
+
+		self.output_file.as_ref()
+			.unwrap()
+			.flush()
+			.map_err( |e| GeneratorError::Write( e.to_string() ) )?;
+
+		Ok(())
+	} // }}}
+}
+
+impl GeneratorImpl for CsvGenerator {
+	fn new(data: &GeneratorCreationData) -> Result<Self, GeneratorError> where Self: Sized { // {{{
+		// fields couldn't be told apart from quotes or rows anymore
+		if data.csv_delimiter == data.csv_quote {
+			return Err( GeneratorError::InvalidOptions( format!(
+				"the csv delimiter and quote can't both be '{}'",
+				data.csv_delimiter,
+			) ) );
+		}
+
+		if [data.csv_delimiter, data.csv_quote].iter().any( |c| *c == '\n' || *c == '\r' ) {
+			return Err( GeneratorError::InvalidOptions(
+				"the csv delimiter and quote can't be line breaks".to_string(),
+			) );
+		}
+
+		Ok( CsvGenerator {
+			row_count: 0,
+			output_file: None,
+			delimiter: data.csv_delimiter,
+			quote: data.csv_quote,
+			initialized: false,
+		} )
+	} // }}}
+
+	fn init(
+		&mut self,
+		_table_name: String,
+		_table_comment: Option<String>,
+		row_count: usize,
+		output_file: File,
+	) -> Result<(), GeneratorError> { // {{{
+		self.row_count = row_count;
+		self.output_file = Some(output_file);
+		self.initialized = true;
+
+		Ok(())
+	} // }}}
+
+	fn format_cell_value(&mut self, value: &CellValue) -> Result<String, GeneratorError> { // {{{
+		Ok( match value {
+			CellValue::Int(value) => value.to_string(),
+
+			CellValue::UnsignedInt(value) => value.to_string(),
+
+			CellValue::Float(value) => value.to_string(),
+
+			CellValue::Decimal(value) => value.to_string(),
+
+			CellValue::String(value) => self.escape_field(value),
+
+			CellValue::Boolean(value) => value.to_string(),
+
+			CellValue::Date(value) => self.escape_field( &value.format(DATE_FORMAT).to_string() ),
+			CellValue::Time(value) => self.escape_field( &value.format(TIME_FORMAT).to_string() ),
+			CellValue::DateTime(value) => self.escape_field( &value.format(DATE_TIME_FORMAT).to_string() ),
+
+			CellValue::Null => "".to_string(),
+		} )
+	} // }}}
+
+	fn generate(&mut self, data: GeneratorData) -> Result<(), GeneratorError> { // {{{
+		if !self.initialized {
+			return Err( GeneratorError::Uninitialized );
+		}
+
+		self.generate_header(&data)?;
+
+		let mut row_data: Vec<&CellValue> = Vec::with_capacity( data.len() );
+
+		for i in 0..self.row_count {
+			for row in data.iter() {
+				row_data.push( &row.data[i] );
+			}
+
+			self.generate_row(&row_data)?;
+
+			row_data.clear();
+		}
+
+		Ok(())
+	} // }}}
+}
+
+#[cfg(test)]
+mod tests {
+	use tempfile::tempfile;
+	use std::io::{ Read, Seek, SeekFrom };
+
+	use crate::parser::config::ColumnType;
+	use super::*;
+
+	const ROW_COUNT: usize = 2;
+	const TABLE_NAME: &str = "test_table";
+
+	fn read_output(file: &mut File) -> String { // {{{
+		let mut output = String::with_capacity(50);
+
+		file.seek( SeekFrom::Start(0) ).unwrap();
+		file.read_to_string(&mut output).unwrap();
+
+		output
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_does_not_quote_plain_strings() -> Result<(), GeneratorError> { // {{{
+		let mut sut = CsvGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value( &CellValue::String( "plain".to_string() ) )?;
+
+		assert_eq!("plain", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_quotes_strings_containing_the_delimiter() -> Result<(), GeneratorError> { // {{{
+		let mut sut = CsvGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value( &CellValue::String( "a,b".to_string() ) )?;
+
+		assert_eq!("\"a,b\"", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_escapes_quotes_by_doubling_them() -> Result<(), GeneratorError> { // {{{
+		let mut sut = CsvGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value( &CellValue::String( "say \"hi\"".to_string() ) )?;
+
+		assert_eq!("\"say \"\"hi\"\"\"", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_writes_null_as_empty_field() -> Result<(), GeneratorError> { // {{{
+		let mut sut = CsvGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value(&CellValue::Null)?;
+
+		assert_eq!("", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_uses_the_configured_characters() -> Result<(), GeneratorError> { // {{{
+		let mut sut = CsvGenerator::new( &GeneratorCreationData {
+			csv_delimiter: ';',
+			csv_quote: '\'',
+			..GeneratorCreationData::default()
+		} )?;
+
+		let result = sut.format_cell_value( &CellValue::String( "it's;here".to_string() ) )?;
+
+		assert_eq!("'it''s;here'", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_writes_a_header_and_the_rows() -> Result<(), GeneratorError> { // {{{
+		let mut file = tempfile().unwrap();
+		let data = vec![
+			ColumnData {
+				name: "id".to_string(),
+				r#type: ColumnType::UnsignedInt,
+				data: vec![ CellValue::UnsignedInt(1), CellValue::UnsignedInt(2) ],
+				comment: None,
+			},
+			ColumnData {
+				name: "name".to_string(),
+				r#type: ColumnType::String(10),
+				data: vec![
+					CellValue::String( "First".to_string() ),
+					CellValue::String( "Second, Third".to_string() ),
+				],
+				comment: None,
+			},
+		];
+
+		let mut sut = CsvGenerator::new( &GeneratorCreationData::default() )?;
+		sut.init(
+			TABLE_NAME.to_string(),
+			None,
+			ROW_COUNT,
+			file.try_clone().unwrap(),
+		)?;
+
+		sut.generate(data)?;
+
+		assert_eq!(
+			"id,name\r\n1,First\r\n2,\"Second, Third\"\r\n",
+			read_output(&mut file),
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_the_delimiter_is_the_quote() { // {{{
+		let result = CsvGenerator::new( &GeneratorCreationData {
+			csv_delimiter: '"',
+			..GeneratorCreationData::default()
+		} );
+
+		assert!( matches!( result, Err( GeneratorError::InvalidOptions(_) ) ) );
+	} // }}}
+
+	#[test]
+	fn test_generate_should_return_error_when_uninitialized() -> Result<(), GeneratorError> { // {{{
+		let mut sut = CsvGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.generate( vec![] );
+
+		assert!( matches!( result, Err(GeneratorError::Uninitialized) ) );
+
+		Ok(())
+	} // }}}
+}
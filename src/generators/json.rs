@@ -0,0 +1,329 @@
+use log::debug;
+use std::{
+	fs::File,
+	io::Write,
+};
+
+use crate::generator::{
+	CellValue,
+	ColumnData,
+	GeneratorCreationData,
+	GeneratorData,
+	GeneratorError,
+	GeneratorImpl,
+	DATE_FORMAT,
+	DATE_TIME_FORMAT,
+	TIME_FORMAT,
+};
+
+pub struct JsonGenerator {
+	row_count: usize,
+	output_file: Option<File>,
+	compact: bool,
+	newline_delimited: bool,
+	initialized: bool,
+}
+
+impl JsonGenerator {
+	fn write_to_output(&mut self, data: String) -> Result<(), GeneratorError> { // {{{
+		self.output_file.as_ref()
+			.unwrap()
+			.write( data.as_bytes() )
+			.map_err( |e| GeneratorError::Write( e.to_string() ) )?;
+
+		Ok(())
+	} // }}}
+
+	/// Escapes `value` so it can be placed inside a json string, including the
+	/// surrounding quotes.
+	fn escape_string(value: &str) -> String { // {{{
+		let mut escaped = String::with_capacity( value.len() + 2 );
+
+		escaped.push('"');
+
+		for c in value.chars() {
+			match c {
+				'"' => escaped.push_str("\\\""),
+				'\\' => escaped.push_str("\\\\"),
+				'\n' => escaped.push_str("\\n"),
+				'\r' => escaped.push_str("\\r"),
+				'\t' => escaped.push_str("\\t"),
+				'\u{08}' => escaped.push_str("\\b"),
+				'\u{0C}' => escaped.push_str("\\f"),
+				c if c < '\u{20}' => escaped.push_str( &format!("\\u{:04x}", c as u32) ),
+				c => escaped.push(c),
+			}
+		}
+
+		escaped.push('"');
+
+		escaped
+	} // }}}
+
+	/// Returns the object for a single row. Rows are only indented when they
+	/// are part of a pretty printed array, because every object in newline
+	/// delimited json has to be on a single line.
+	fn generate_object(&mut self, columns: &[ColumnData], rows: &[&CellValue]) -> Result<String, GeneratorError> { // {{{
+		debug!("writing data: {:?}", rows);
+
+		let pretty = !self.compact && !self.newline_delimited;
+		let mut fields = Vec::with_capacity( rows.len() );
+
+		for (column, row) in columns.iter().zip(rows) {
+			let separator = if self.compact { ":" } else { ": " };
+
+			fields.push( format!(
+				"{}{}{}",
+				Self::escape_string(&column.name),
+				separator,
+				self.format_cell_value(row)?,
+			) );
+		}
+
+		let object = if pretty {
+			format!("{{\n\t\t{}\n\t}}", fields.join(",\n\t\t") )
+		} else if self.compact {
+			format!("{{{}}}", fields.join(",") )
+		} else {
+			format!("{{ {} }}", fields.join(", ") )
+		};
+
+		Ok(object)
+	} // }}}
+}
+
+impl GeneratorImpl for JsonGenerator {
+	fn new(data: &GeneratorCreationData) -> Result<Self, GeneratorError> where Self: Sized { // {{{
+		Ok( JsonGenerator {
+			row_count: 0,
+			output_file: None,
+			compact: data.json_compact,
+			newline_delimited: data.json_newline_delimited,
+			initialized: false,
+		} )
+	} // }}}
+
+	fn init(
+		&mut self,
+		_table_name: String,
+		_table_comment: Option<String>,
+		row_count: usize,
+		output_file: File,
+	) -> Result<(), GeneratorError> { // {{{
+		self.row_count = row_count;
+		self.output_file = Some(output_file);
+		self.initialized = true;
+
+		Ok(())
+	} // }}}
+
+	fn format_cell_value(&mut self, value: &CellValue) -> Result<String, GeneratorError> { // {{{
+		Ok( match value {
+			CellValue::Int(value) => value.to_string(),
+
+			CellValue::UnsignedInt(value) => value.to_string(),
+
+			// json has no representation for NaN and infinity
+			CellValue::Float(value) if !value.is_finite() => "null".to_string(),
+			CellValue::Float(value) => value.to_string(),
+
+			CellValue::Decimal(value) => value.to_string(),
+
+			CellValue::String(value) => Self::escape_string(value),
+
+			CellValue::Boolean(value) => value.to_string(),
+
+			// json has no date type, so dates are written as ISO 8601 strings
+			CellValue::Date(value) => format!( "\"{}\"", value.format(DATE_FORMAT) ),
+			CellValue::Time(value) => format!( "\"{}\"", value.format(TIME_FORMAT) ),
+			CellValue::DateTime(value) => format!( "\"{}\"", value.format(DATE_TIME_FORMAT) ),
+
+			CellValue::Null => "null".to_string(),
+		} )
+	} // }}}
+
+	fn generate(&mut self, data: GeneratorData) -> Result<(), GeneratorError> { // {{{
+		if !self.initialized {
+			return Err( GeneratorError::Uninitialized );
+		}
+
+		let (start, separator, end) = match (self.newline_delimited, self.compact) {
+			(true, _) => ("", "\n", "\n"),
+			(false, true) => ("[", ",", "]\n"),
+			(false, false) => ("[\n\t", ", ", "\n]\n"),
+		};
+
+		self.write_to_output( start.to_string() )?;
+
+		let mut row_data: Vec<&CellValue> = Vec::with_capacity( data.len() );
+
+		for i in 0..self.row_count {
+			for row in data.iter() {
+				row_data.push( &row.data[i] );
+			}
+
+			if i > 0 {
+				self.write_to_output( separator.to_string() )?;
+			}
+
+			let object = self.generate_object(&data, &row_data)?;
+
+			self.write_to_output(object)?;
+
+			row_data.clear();
+		}
+
+		self.write_to_output( end.to_string() )?;
+
+		self.output_file.as_ref()
+			.unwrap()
+			.flush()
+			.map_err( |e| GeneratorError::Write( e.to_string() ) )?;
+
+		Ok(())
+	} // }}}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveTime;
+	use tempfile::tempfile;
+	use std::io::{ Read, Seek, SeekFrom };
+
+	use crate::{
+		decimal::Decimal,
+		parser::config::ColumnType,
+	};
+	use super::*;
+
+	const ROW_COUNT: usize = 2;
+	const TABLE_NAME: &str = "test_table";
+
+	fn data() -> GeneratorData { // {{{
+		vec![
+			ColumnData {
+				name: "id".to_string(),
+				r#type: ColumnType::UnsignedInt,
+				data: vec![ CellValue::UnsignedInt(1), CellValue::UnsignedInt(2) ],
+				comment: None,
+			},
+			ColumnData {
+				name: "active".to_string(),
+				r#type: ColumnType::Boolean,
+				data: vec![ CellValue::Boolean(true), CellValue::Boolean(false) ],
+				comment: None,
+			},
+		]
+	} // }}}
+
+	fn generate(creation_data: &GeneratorCreationData) -> Result<String, GeneratorError> { // {{{
+		let mut file = tempfile().unwrap();
+
+		let mut sut = JsonGenerator::new(creation_data)?;
+		sut.init(
+			TABLE_NAME.to_string(),
+			None,
+			ROW_COUNT,
+			file.try_clone().unwrap(),
+		)?;
+
+		sut.generate( data() )?;
+
+		let mut output = String::with_capacity(50);
+
+		file.seek( SeekFrom::Start(0) ).unwrap();
+		file.read_to_string(&mut output).unwrap();
+
+		Ok(output)
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_escapes_strings() -> Result<(), GeneratorError> { // {{{
+		let mut sut = JsonGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value(
+			&CellValue::String( "a \"quoted\"\\\n\u{01}".to_string() )
+		)?;
+
+		assert_eq!("\"a \\\"quoted\\\"\\\\\\n\\u0001\"", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_keeps_numbers_and_booleans_native() -> Result<(), GeneratorError> { // {{{
+		let mut sut = JsonGenerator::new( &GeneratorCreationData::default() )?;
+
+		assert_eq!( "-5", sut.format_cell_value( &CellValue::Int(-5) )? );
+		assert_eq!( "1.5", sut.format_cell_value( &CellValue::Float(1.5) )? );
+		assert_eq!( "true", sut.format_cell_value( &CellValue::Boolean(true) )? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_writes_decimals_exactly() -> Result<(), GeneratorError> { // {{{
+		let mut sut = JsonGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value( &CellValue::Decimal( Decimal::new(100000000000000000000010, 1) ) )?;
+
+		assert_eq!("10000000000000000000001.0", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_writes_times_as_iso_8601_strings() -> Result<(), GeneratorError> { // {{{
+		let mut sut = JsonGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value(
+			&CellValue::Time( NaiveTime::from_hms_opt(8, 5, 0).unwrap() )
+		)?;
+
+		assert_eq!("\"08:05:00\"", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_writes_a_pretty_array() -> Result<(), GeneratorError> { // {{{
+		let output = generate( &GeneratorCreationData::default() )?;
+
+		assert_eq!(
+			"[\n\t{\n\t\t\"id\": 1,\n\t\t\"active\": true\n\t}, {\n\t\t\"id\": 2,\n\t\t\"active\": false\n\t}\n]\n",
+			output,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_writes_a_compact_array() -> Result<(), GeneratorError> { // {{{
+		let output = generate( &GeneratorCreationData {
+			json_compact: true,
+			..GeneratorCreationData::default()
+		} )?;
+
+		assert_eq!(
+			"[{\"id\":1,\"active\":true},{\"id\":2,\"active\":false}]\n",
+			output,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_writes_newline_delimited_objects() -> Result<(), GeneratorError> { // {{{
+		let output = generate( &GeneratorCreationData {
+			json_newline_delimited: true,
+			..GeneratorCreationData::default()
+		} )?;
+
+		assert_eq!(
+			"{ \"id\": 1, \"active\": true }\n{ \"id\": 2, \"active\": false }\n",
+			output,
+		);
+
+		Ok(())
+	} // }}}
+}
@@ -1 +1,4 @@
+pub mod csv;
+pub mod json;
 pub mod tsql;
+pub mod xml;
@@ -4,21 +4,32 @@ use std::{
 	io::Write,
 };
 
-use crate::generator::{
-	CellValue,
-	ColumnData,
-	GeneratorData,
-	GeneratorError,
-	GeneratorImpl,
+use crate::{
+	generator::{
+		CellValue,
+		ColumnData,
+		GeneratorCreationData,
+		GeneratorData,
+		GeneratorError,
+		GeneratorImpl,
+		DATE_FORMAT,
+		DATE_TIME_FORMAT,
+		TIME_FORMAT,
+	},
+	parser::config::ColumnType,
 };
 
 pub struct TsqlGenerator {
 	table_name: String,
+	table_comment: Option<String>,
 	row_count: usize,
 	output_file: Option<File>,
 	/// Contains the string `insert into <table> (<columns>) values `,
 	/// including the trailing space
 	columns: String,
+	/// Whether a check constraint should be added to every column of an enum
+	/// type
+	enum_checks: bool,
 	initialized: bool,
 }
 
@@ -34,8 +45,8 @@ impl TsqlGenerator {
 		Ok(())
 	} // }}}
 
-	fn generate_columns(&mut self, columns: &Vec<ColumnData>) -> Result<(), GeneratorError> { // {{{
-		let columns_string = columns.into_iter()
+	fn generate_columns(&mut self, columns: &[ColumnData]) -> Result<(), GeneratorError> { // {{{
+		let columns_string = columns.iter()
 			.map( |c| c.name.clone() )
 			.collect::< Vec<String> >()
 			.join(", ");
@@ -49,6 +60,68 @@ impl TsqlGenerator {
 		Ok(())
 	} // }}}
 
+	/// Writes the doc comments of the table and its columns as sql comments,
+	/// one line per comment line
+	fn generate_comments(&mut self, columns: &[ColumnData]) -> Result<(), GeneratorError> { // {{{
+		let mut comments = String::new();
+
+		if let Some(comment) = &self.table_comment {
+			for line in comment.lines() {
+				comments.push_str( &format!("-- {}\n", line) );
+			}
+		}
+
+		for column in columns {
+			let Some(comment) = &column.comment else {
+				continue;
+			};
+
+			for (i, line) in comment.lines().enumerate() {
+				match i {
+					0 => comments.push_str( &format!("-- {}: {}\n", column.name, line) ),
+					_ => comments.push_str( &format!("--   {}\n", line) ),
+				}
+			}
+		}
+
+		if !comments.is_empty() {
+			self.write_to_output(comments)?;
+		}
+
+		Ok(())
+	} // }}}
+
+	/// Writes a check constraint for every column of an enum type, so the
+	/// column only accepts the variants of its enum. Constraints that already
+	/// exist are skipped, so the script can be run more than once.
+	fn generate_enum_checks(&mut self, columns: &[ColumnData]) -> Result<(), GeneratorError> { // {{{
+		let mut checks = String::new();
+
+		for column in columns {
+			let ColumnType::Enum(enum_type) = &column.r#type else {
+				continue;
+			};
+
+			let variants = enum_type.variants.iter()
+				.map( |variant| format!("'{}'", variant.name) )
+				.collect::< Vec<String> >()
+				.join(", ");
+
+			checks.push_str( &format!(
+				"if not exists (select * from sys.check_constraints where name = 'CK_{0}_{1}') alter table {0} add constraint CK_{0}_{1} check ({1} in ({2}));\n",
+				self.table_name,
+				column.name,
+				variants,
+			) );
+		}
+
+		if !checks.is_empty() {
+			self.write_to_output(checks)?;
+		}
+
+		Ok(())
+	} // }}}
+
 	fn generate_row(&mut self, rows: &Vec<&CellValue>) -> Result<(), GeneratorError> { // {{{
 		debug!("writing data: {:?}", rows);
 
@@ -56,8 +129,8 @@ impl TsqlGenerator {
 
 		self.write_to_output( "(".to_string() )?;
 
-		for i in 0..rows.len() - 1 {
-			let data = self.format_cell_value( rows[i] )?;
+		for row in &rows[ ..rows.len() - 1 ] {
+			let data = self.format_cell_value(row)?;
 
 			self.write_to_output(data)?;
 			self.write_to_output( ", ".to_string() )?;
@@ -79,18 +152,27 @@ impl TsqlGenerator {
 }
 
 impl GeneratorImpl for TsqlGenerator {
-	fn new() -> Result<Self, GeneratorError> where Self: Sized { // {{{
+	fn new(data: &GeneratorCreationData) -> Result<Self, GeneratorError> where Self: Sized { // {{{
 		Ok( TsqlGenerator {
 			table_name: "".to_string(),
+			table_comment: None,
 			row_count: 0,
 			output_file: None,
 			columns: "".to_string(),
+			enum_checks: data.tsql_enum_checks,
 			initialized: false,
 		} )
 	} // }}}
 
-	fn init(&mut self, table_name: String, row_count: usize, output_file: File) -> Result<(), GeneratorError> { // {{{
+	fn init(
+		&mut self,
+		table_name: String,
+		table_comment: Option<String>,
+		row_count: usize,
+		output_file: File,
+	) -> Result<(), GeneratorError> { // {{{
 		self.table_name = table_name;
+		self.table_comment = table_comment;
 		self.row_count = row_count;
 		self.output_file = Some(output_file);
 		self.initialized = true;
@@ -104,12 +186,25 @@ impl GeneratorImpl for TsqlGenerator {
 
 			CellValue::UnsignedInt(value) => value.to_string(),
 
+			// sql server has no representation for NaN and infinity
+			CellValue::Float(value) if !value.is_finite() => "null".to_string(),
 			CellValue::Float(value) => value.to_string(),
 
-			CellValue::String(value) => format!("'{}'", value),
+			CellValue::Decimal(value) => value.to_string(),
+
+			// quotes are escaped by doubling them, like in O''Markey
+			CellValue::String(value) => format!( "'{}'", value.replace('\'', "''") ),
 
 			CellValue::Boolean(true) => "1".to_string(),
 			CellValue::Boolean(false) => "0".to_string(),
+
+			// ISO 8601 literals are converted independent of the language and
+			// date format settings of the server
+			CellValue::Date(value) => format!( "'{}'", value.format(DATE_FORMAT) ),
+			CellValue::Time(value) => format!( "'{}'", value.format(TIME_FORMAT) ),
+			CellValue::DateTime(value) => format!( "'{}'", value.format(DATE_TIME_FORMAT) ),
+
+			CellValue::Null => "null".to_string(),
 		} )
 	} // }}}
 
@@ -118,6 +213,12 @@ impl GeneratorImpl for TsqlGenerator {
 			return Err( GeneratorError::Uninitialized );
 		}
 
+		self.generate_comments(&data)?;
+
+		if self.enum_checks {
+			self.generate_enum_checks(&data)?;
+		}
+
 		self.generate_columns(&data)?;
 
 		// allocate vector with length equal to the amount of columns
@@ -139,10 +240,13 @@ impl GeneratorImpl for TsqlGenerator {
 
 #[cfg(test)]
 mod tests {
+	use chrono::NaiveDate;
 	use tempfile::tempfile;
 	use std::io::{ Read, Seek, SeekFrom };
 
-	use crate::parser::config::ColumnType;
+	use std::sync::Arc;
+
+	use crate::parser::config::{ EnumType, EnumVariant };
 	use super::*;
 
 	const ROW_COUNT: usize = 10;
@@ -191,6 +295,7 @@ mod tests {
 				data: data_1.iter()
 					.map( |value| CellValue::String( value.clone() ) )
 					.collect(),
+				comment: None,
 			}; // }}}
 
 			let column_2: ColumnData = ColumnData { // {{{
@@ -199,6 +304,7 @@ mod tests {
 				data: data_2.iter()
 					.map( |value| CellValue::String( value.clone() ) )
 					.collect(),
+				comment: None,
 			}; // }}}
 
 			// create a copy of column_1 and column_2, because String doesn't
@@ -209,6 +315,7 @@ mod tests {
 				data: data_1.iter()
 					.map( |value| CellValue::String( value.clone() ) )
 					.collect(),
+				comment: None,
 			}; // }}}
 
 			let column_4: ColumnData = ColumnData { // {{{
@@ -217,6 +324,7 @@ mod tests {
 				data: data_2.iter()
 					.map( |value| CellValue::String( value.clone() ) )
 					.collect(),
+				comment: None,
 			}; // }}}
 
 			Self {
@@ -235,9 +343,10 @@ mod tests {
 		// TODO: check if file actually deletes after usage
 		let file = tempfile().unwrap();
 
-		let mut sut = TsqlGenerator::new()?;
+		let mut sut = TsqlGenerator::new( &GeneratorCreationData::default() )?;
 		sut.init(
 			TABLE_NAME.to_string(),
+			None,
 			ROW_COUNT,
 			file,
 		)?;
@@ -268,9 +377,10 @@ mod tests {
 			setup.column_2.name,
 		);
 
-		let mut sut = TsqlGenerator::new()?;
+		let mut sut = TsqlGenerator::new( &GeneratorCreationData::default() )?;
 		sut.init(
 			TABLE_NAME.to_string(),
+			None,
 			ROW_COUNT,
 			file.try_clone().unwrap(),
 		)?;
@@ -299,6 +409,119 @@ mod tests {
 		Ok(())
 	} // }}}
 
+	#[test]
+	fn test_format_cell_value_writes_null() -> Result<(), GeneratorError> { // {{{
+		let mut sut = TsqlGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value(&CellValue::Null)?;
+
+		assert_eq!("null", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_writes_nan_and_infinity_as_null() -> Result<(), GeneratorError> { // {{{
+		let mut sut = TsqlGenerator::new( &GeneratorCreationData::default() )?;
+
+		for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+			assert_eq!( "null", sut.format_cell_value( &CellValue::Float(value) )? );
+		}
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_escapes_quotes_in_strings() -> Result<(), GeneratorError> { // {{{
+		let mut sut = TsqlGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value( &CellValue::String( "Orran O'Markey".to_string() ) )?;
+
+		assert_eq!("'Orran O''Markey'", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_writes_dates_as_iso_8601_literals() -> Result<(), GeneratorError> { // {{{
+		let date = NaiveDate::from_ymd_opt(2021, 11, 18).unwrap();
+		let mut sut = TsqlGenerator::new( &GeneratorCreationData::default() )?;
+
+		assert_eq!( "'2021-11-18'", sut.format_cell_value( &CellValue::Date(date) )? );
+		assert_eq!(
+			"'2021-11-18T01:49:49'",
+			sut.format_cell_value( &CellValue::DateTime( date.and_hms_opt(1, 49, 49).unwrap() ) )?,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_comments_writes_the_table_and_column_comments() -> Result<(), GeneratorError> { // {{{
+		let mut setup = Setup::new();
+		let mut file = tempfile().unwrap();
+
+		setup.column_data[0].comment = Some( "The first column\nover two lines".to_string() );
+
+		let mut sut = TsqlGenerator::new( &GeneratorCreationData::default() )?;
+		sut.init(
+			TABLE_NAME.to_string(),
+			Some( "A table for testing".to_string() ),
+			ROW_COUNT,
+			file.try_clone().unwrap(),
+		)?;
+
+		sut.generate_comments(&setup.column_data)?;
+
+		let mut output = String::with_capacity(100);
+
+		file.seek( SeekFrom::Start(0) ).unwrap();
+		file.read_to_string(&mut output).unwrap();
+
+		assert_eq!(
+			"-- A table for testing\n-- test_column_1: The first column\n--   over two lines\n",
+			output,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_enum_checks_writes_a_check_for_enum_columns() -> Result<(), GeneratorError> { // {{{
+		let mut setup = Setup::new();
+		let mut file = tempfile().unwrap();
+
+		setup.column_data[1].r#type = ColumnType::Enum( Arc::new( EnumType::new(
+			"Status".to_string(),
+			vec![
+				EnumVariant::new( "Active".to_string(), 1 ),
+				EnumVariant::new( "Closed".to_string(), 1 ),
+			],
+		) ) );
+
+		let mut sut = TsqlGenerator::new( &GeneratorCreationData::default() )?;
+		sut.init(
+			TABLE_NAME.to_string(),
+			None,
+			ROW_COUNT,
+			file.try_clone().unwrap(),
+		)?;
+
+		sut.generate_enum_checks(&setup.column_data)?;
+
+		let mut output = String::with_capacity(100);
+
+		file.seek( SeekFrom::Start(0) ).unwrap();
+		file.read_to_string(&mut output).unwrap();
+
+		assert_eq!(
+			"if not exists (select * from sys.check_constraints where name = 'CK_test_table_test_column_2') alter table test_table add constraint CK_test_table_test_column_2 check (test_column_2 in ('Active', 'Closed'));\n",
+			output,
+		);
+
+		Ok(())
+	} // }}}
+
 	// TODO: write a unit test for TsqlGenerator.generate(self, GeneratorData).
 	// Probably need some way of mocking for it to be a unit test?
 }
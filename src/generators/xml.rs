@@ -0,0 +1,353 @@
+use log::debug;
+use std::{
+	fs::File,
+	io::Write,
+};
+
+use crate::generator::{
+	CellValue,
+	ColumnData,
+	GeneratorCreationData,
+	GeneratorData,
+	GeneratorError,
+	GeneratorImpl,
+	DATE_FORMAT,
+	DATE_TIME_FORMAT,
+	TIME_FORMAT,
+};
+
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" ?>\n";
+
+pub struct XmlGenerator {
+	row_count: usize,
+	output_file: Option<File>,
+	root_element: String,
+	row_element: String,
+	use_attributes: bool,
+	initialized: bool,
+}
+
+impl XmlGenerator {
+	fn write_to_output(&mut self, data: String) -> Result<(), GeneratorError> { // {{{
+		self.output_file.as_ref()
+			.unwrap()
+			.write( data.as_bytes() )
+			.map_err( |e| GeneratorError::Write( e.to_string() ) )?;
+
+		Ok(())
+	} // }}}
+
+	/// Replaces all characters with a special meaning in xml with their
+	/// entity. Quotes are escaped as well, so the result can also be used as
+	/// an attribute value. Inside attributes, line breaks and tabs are written
+	/// as character references, because parsers would replace them with spaces
+	/// otherwise. Characters xml 1.0 doesn't allow at all, like most control
+	/// characters, are replaced with U+FFFD.
+	fn escape(value: &str, in_attribute: bool) -> String { // {{{
+		let mut escaped = String::with_capacity( value.len() );
+
+		for c in value.chars() {
+			match c {
+				'&' => escaped.push_str("&amp;"),
+				'<' => escaped.push_str("&lt;"),
+				'>' => escaped.push_str("&gt;"),
+				'"' => escaped.push_str("&quot;"),
+				'\'' => escaped.push_str("&apos;"),
+				'\n' if in_attribute => escaped.push_str("&#10;"),
+				'\r' if in_attribute => escaped.push_str("&#13;"),
+				'\t' if in_attribute => escaped.push_str("&#9;"),
+				'\t' | '\n' | '\r' => escaped.push(c),
+				'\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => escaped.push(char::REPLACEMENT_CHARACTER),
+				c => escaped.push(c),
+			}
+		}
+
+		escaped
+	} // }}}
+
+	fn generate_row(&mut self, columns: &[ColumnData], rows: &[&CellValue]) -> Result<(), GeneratorError> { // {{{
+		debug!("writing data: {:?}", rows);
+
+		let mut output = String::with_capacity(100);
+
+		if self.use_attributes {
+			output.push_str( &format!("\t<{}", self.row_element) );
+
+			for (column, row) in columns.iter().zip(rows) {
+				// a missing attribute means the value is null
+				if let CellValue::Null = row {
+					continue;
+				}
+
+				let value = self.format_cell_value(row)?;
+
+				output.push_str( &format!(" {}=\"{}\"", column.name, value) );
+			}
+
+			output.push_str(" />\n");
+		} else {
+			output.push_str( &format!("\t<{}>\n", self.row_element) );
+
+			for (column, row) in columns.iter().zip(rows) {
+				if let CellValue::Null = row {
+					output.push_str( &format!("\t\t<{} />\n", column.name) );
+
+					continue;
+				}
+
+				let value = self.format_cell_value(row)?;
+
+				output.push_str( &format!(
+					"\t\t<{}>{}</{}>\n",
+					column.name,
+					value,
+					column.name,
+				) );
+			}
+
+			output.push_str( &format!("\t</{}>\n", self.row_element) );
+		}
+
+		self.write_to_output(output)?;
+
+		Ok(())
+	} // }}}
+}
+
+impl GeneratorImpl for XmlGenerator {
+	fn new(data: &GeneratorCreationData) -> Result<Self, GeneratorError> where Self: Sized { // {{{
+		Ok( XmlGenerator {
+			row_count: 0,
+			output_file: None,
+			root_element: data.xml_root.clone(),
+			row_element: data.xml_row.clone(),
+			use_attributes: data.xml_attributes,
+			initialized: false,
+		} )
+	} // }}}
+
+	fn init(
+		&mut self,
+		_table_name: String,
+		_table_comment: Option<String>,
+		row_count: usize,
+		output_file: File,
+	) -> Result<(), GeneratorError> { // {{{
+		self.row_count = row_count;
+		self.output_file = Some(output_file);
+		self.initialized = true;
+
+		Ok(())
+	} // }}}
+
+	fn format_cell_value(&mut self, value: &CellValue) -> Result<String, GeneratorError> { // {{{
+		Ok( match value {
+			CellValue::Int(value) => value.to_string(),
+
+			CellValue::UnsignedInt(value) => value.to_string(),
+
+			CellValue::Float(value) => value.to_string(),
+
+			CellValue::Decimal(value) => value.to_string(),
+
+			CellValue::String(value) => Self::escape(value, self.use_attributes),
+
+			CellValue::Boolean(value) => value.to_string(),
+
+			// the formats match the xs:date, xs:time and xs:dateTime types
+			CellValue::Date(value) => value.format(DATE_FORMAT).to_string(),
+			CellValue::Time(value) => value.format(TIME_FORMAT).to_string(),
+			CellValue::DateTime(value) => value.format(DATE_TIME_FORMAT).to_string(),
+
+			CellValue::Null => "".to_string(),
+		} )
+	} // }}}
+
+	fn generate(&mut self, data: GeneratorData) -> Result<(), GeneratorError> { // {{{
+		if !self.initialized {
+			return Err( GeneratorError::Uninitialized );
+		}
+
+		self.write_to_output( XML_DECLARATION.to_string() )?;
+		self.write_to_output( format!("<{}>\n", self.root_element) )?;
+
+		let mut row_data: Vec<&CellValue> = Vec::with_capacity( data.len() );
+
+		for i in 0..self.row_count {
+			for row in data.iter() {
+				row_data.push( &row.data[i] );
+			}
+
+			self.generate_row(&data, &row_data)?;
+
+			row_data.clear();
+		}
+
+		self.write_to_output( format!("</{}>\n", self.root_element) )?;
+
+		self.output_file.as_ref()
+			.unwrap()
+			.flush()
+			.map_err( |e| GeneratorError::Write( e.to_string() ) )?;
+
+		Ok(())
+	} // }}}
+}
+
+#[cfg(test)]
+mod tests {
+	use tempfile::tempfile;
+	use std::io::{ Read, Seek, SeekFrom };
+
+	use crate::parser::config::ColumnType;
+	use super::*;
+
+	const ROW_COUNT: usize = 1;
+	const TABLE_NAME: &str = "test_table";
+
+	fn data() -> GeneratorData { // {{{
+		vec![
+			ColumnData {
+				name: "id".to_string(),
+				r#type: ColumnType::UnsignedInt,
+				data: vec![ CellValue::UnsignedInt(1) ],
+				comment: None,
+			},
+			ColumnData {
+				name: "name".to_string(),
+				r#type: ColumnType::String(20),
+				data: vec![ CellValue::String( "Tom & \"Jerry\"".to_string() ) ],
+				comment: None,
+			},
+		]
+	} // }}}
+
+	fn generate(creation_data: &GeneratorCreationData) -> Result<String, GeneratorError> { // {{{
+		let mut file = tempfile().unwrap();
+
+		let mut sut = XmlGenerator::new(creation_data)?;
+		sut.init(
+			TABLE_NAME.to_string(),
+			None,
+			ROW_COUNT,
+			file.try_clone().unwrap(),
+		)?;
+
+		sut.generate( data() )?;
+
+		let mut output = String::with_capacity(50);
+
+		file.seek( SeekFrom::Start(0) ).unwrap();
+		file.read_to_string(&mut output).unwrap();
+
+		Ok(output)
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_escapes_entities() -> Result<(), GeneratorError> { // {{{
+		let mut sut = XmlGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value(
+			&CellValue::String( "<a href='x'>&</a>".to_string() )
+		)?;
+
+		assert_eq!("&lt;a href=&apos;x&apos;&gt;&amp;&lt;/a&gt;", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_should_keep_line_breaks_and_tabs_in_elements() -> Result<(), GeneratorError> { // {{{
+		let mut sut = XmlGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value( &CellValue::String( "a\tb\r\nc".to_string() ) )?;
+
+		assert_eq!("a\tb\r\nc", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_should_write_line_breaks_and_tabs_in_attributes_as_references() -> Result<(), GeneratorError> { // {{{
+		let mut sut = XmlGenerator::new( &GeneratorCreationData {
+			xml_attributes: true,
+			..GeneratorCreationData::default()
+		} )?;
+
+		let result = sut.format_cell_value( &CellValue::String( "a\tb\r\nc".to_string() ) )?;
+
+		assert_eq!("a&#9;b&#13;&#10;c", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_format_cell_value_should_replace_characters_xml_does_not_allow() -> Result<(), GeneratorError> { // {{{
+		let mut sut = XmlGenerator::new( &GeneratorCreationData::default() )?;
+
+		let result = sut.format_cell_value( &CellValue::String( "a\u{0}b\u{8}c\u{1b}d\u{ffff}".to_string() ) )?;
+
+		assert_eq!("a\u{fffd}b\u{fffd}c\u{fffd}d\u{fffd}", result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_writes_columns_as_elements() -> Result<(), GeneratorError> { // {{{
+		let output = generate( &GeneratorCreationData::default() )?;
+
+		assert_eq!(
+			format!(
+				"{}<data>\n\t<entry>\n\t\t<id>1</id>\n\t\t<name>Tom &amp; &quot;Jerry&quot;</name>\n\t</entry>\n</data>\n",
+				XML_DECLARATION,
+			),
+			output,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_row_writes_null_as_self_closing_element() -> Result<(), GeneratorError> { // {{{
+		let mut file = tempfile().unwrap();
+
+		let mut sut = XmlGenerator::new( &GeneratorCreationData::default() )?;
+		sut.init(
+			TABLE_NAME.to_string(),
+			None,
+			ROW_COUNT,
+			file.try_clone().unwrap(),
+		)?;
+
+		sut.generate_row( &data(), &[ &CellValue::UnsignedInt(1), &CellValue::Null ] )?;
+
+		let mut output = String::with_capacity(50);
+
+		file.seek( SeekFrom::Start(0) ).unwrap();
+		file.read_to_string(&mut output).unwrap();
+
+		assert_eq!("\t<entry>\n\t\t<id>1</id>\n\t\t<name />\n\t</entry>\n", output);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_writes_columns_as_attributes() -> Result<(), GeneratorError> { // {{{
+		let output = generate( &GeneratorCreationData {
+			xml_root: "rows".to_string(),
+			xml_row: "row".to_string(),
+			xml_attributes: true,
+			..GeneratorCreationData::default()
+		} )?;
+
+		assert_eq!(
+			format!(
+				"{}<rows>\n\t<row id=\"1\" name=\"Tom &amp; &quot;Jerry&quot;\" />\n</rows>\n",
+				XML_DECLARATION,
+			),
+			output,
+		);
+
+		Ok(())
+	} // }}}
+}
@@ -1,18 +1,53 @@
 use clap::Parser as CliParser;
+use std::process;
 
 use crate::{
-	arguments::Args,
+	arguments::{ Args, Command },
+	diagnostic::Diagnostics,
 	mocker::Mocker,
 };
 
+mod alias;
 mod arguments;
+mod constraint;
+mod constraints;
+mod decimal;
+mod dependency;
+mod diagnostic;
+mod distribution;
 mod generator;
 mod generators;
 mod mocker;
+mod parameter;
 mod parser;
 mod provider;
 mod providers;
 mod registry;
+mod rng;
+
+fn run(args: &Args, mocker: &Mocker) -> anyhow::Result<()> {
+	if let Some( Command::Check { config: path } ) = &args.command {
+		let mut config = mocker.parse_config(path)?;
+
+		mocker.check_config(&mut config)?;
+
+		println!("No errors found in '{}'", path.display());
+
+		return Ok(());
+	}
+
+	let path = args.config.as_ref()
+		.expect("the config should be required when generating");
+	let mut config = mocker.parse_config(path)?;
+
+	mocker.check_config(&mut config)?;
+
+	let generated_data = mocker.generate_mock_data(&mut config)?;
+
+	mocker.write_mock_data(&config, generated_data)?;
+
+	Ok(())
+}
 
 fn main() -> anyhow::Result<()> {
 	env_logger::init();
@@ -20,11 +55,17 @@ fn main() -> anyhow::Result<()> {
 	let args = Args::parse();
 	let mocker = Mocker::new(&args);
 
-	let config = mocker.parse_config()?;
+	if let Err(error) = run(&args, &mocker) {
+		// diagnostics are written like compiler errors, so they shouldn't be
+		// prefixed by anyhow
+		if let Some(diagnostics) = error.downcast_ref::<Diagnostics>() {
+			eprintln!("{}", diagnostics);
 
-	let generated_data = mocker.generate_mock_data(config)?;
+			process::exit(1);
+		}
 
-	mocker.write_mock_data(generated_data)?;
+		return Err(error);
+	}
 
 	Ok(())
 }
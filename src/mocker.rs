@@ -1,21 +1,64 @@
 use log::{ debug, info };
 use std::{
-	collections::HashMap,
+	cell::RefCell,
+	collections::{
+		HashMap,
+		HashSet,
+	},
 	fs,
-	path::Path,
+	path::{ Path, PathBuf },
+	rc::Rc,
 };
 
 use crate::{
-	arguments::Args,
+	arguments::{
+		Args,
+		JSON_LAYOUT_NEWLINE_DELIMITED,
+	},
+	constraint::{
+		ConstraintCreationData,
+		ConstraintError,
+	},
+	constraints::{
+		null::NULL_CONSTRAINT_NAME,
+		primary::PRIMARY_CONSTRAINT_NAME,
+	},
+	dependency::{
+		self,
+		DependencyError,
+	},
+	diagnostic::{
+		Diagnostic,
+		Diagnostics,
+	},
+	parameter,
 	parser::{
-		config::Config,
+		config::{
+			Argument,
+			Column,
+			Config,
+			Constraint,
+			Table,
+		},
+		errors::ParserError,
 		Parser,
 	},
-	provider::ProviderError,
-	generator::{ ColumnData, GeneratorData },
+	provider::{
+		ProviderCreationData,
+		ProviderError,
+	},
+	providers::link::LINK_PROVIDER_NAME,
+	generator::{
+		ColumnData,
+		GeneratorCreationData,
+		MockData,
+	},
 	registry::registrars::{
+		register_constraints,
 		register_providers,
 		register_generators,
+		ConstraintRegistry,
+		ProviderRegistry,
 	},
 };
 
@@ -29,7 +72,50 @@ lazy_static::lazy_static! { // {{{
 	};
 } // }}}
 
-type MockData = HashMap<String, GeneratorData>;
+/// Wraps an error found while generating the values of `column`, so it's
+/// reported with the location of the column.
+fn column_error(error: impl Into<anyhow::Error>, column: &Column) -> anyhow::Error { // {{{
+	let diagnostic = Diagnostic::new( error, column.location.clone() );
+
+	anyhow::Error::new( Diagnostics( vec![diagnostic] ) )
+} // }}}
+
+/// Returns an error for every table and column that has the same name as one
+/// defined before it, located at the later definition.
+fn check_names(config: &Config) -> Vec<Diagnostic> { // {{{
+	let mut diagnostics = Vec::new();
+	let mut tables: HashMap<&str, &Table> = HashMap::new();
+
+	for table in &config.tables {
+		match tables.get( table.name.as_str() ) {
+			Some(first) => {
+				let first_location = first.location.as_ref()
+					.map_or_else( || "another location".to_string(), |location| location.to_string() );
+
+				diagnostics.push( Diagnostic::new(
+					ParserError::DuplicateTable( table.name.clone(), first_location ),
+					table.location.clone(),
+				) );
+			},
+			None => {
+				tables.insert( table.name.as_str(), table );
+			},
+		}
+
+		let mut columns = HashSet::new();
+
+		for column in &table.columns {
+			if !columns.insert( column.name.as_str() ) {
+				diagnostics.push( Diagnostic::new(
+					ParserError::DuplicateColumn( table.name.clone(), column.name.clone() ),
+					column.location.clone(),
+				) );
+			}
+		}
+	}
+
+	diagnostics
+} // }}}
 
 pub struct Mocker<'a> {
 	args: &'a Args,
@@ -40,70 +126,374 @@ impl<'a> Mocker<'a> {
 		Self { args }
 	}
 
-	pub fn parse_config(&self) -> anyhow::Result<Config> { // {{{
-		let parser = Parser::new(&self.args.config)?;
-		let config = parser.parse()?;
+	pub fn parse_config(&self, path: &PathBuf) -> anyhow::Result<Config> { // {{{
+		let mut defines = HashMap::with_capacity( self.args.defines.len() );
+
+		for (name, value) in &self.args.defines {
+			defines.insert( name.clone(), Argument::from_define(value)? );
+		}
+
+		let config = match path.is_dir() {
+			true => Parser::parse_directory(path, defines)?,
+			false => Parser::new(path)?
+				.with_defines(defines)
+				.parse()?,
+		};
 
 		debug!("Parsed config: {:#?}", config);
 
 		Ok(config)
 	} // }}}
 
-	pub fn generate_mock_data(&self, config: Config) -> anyhow::Result<MockData> { // {{{
-		let mut provider_registry = register_providers(&self.args)?;
+	/// Checks the config for errors that can be found before any data is
+	/// generated, like duplicate names, unknown providers, invalid arguments,
+	/// providers that don't fit their column and broken links. Also puts the
+	/// arguments of every provider and constraint in the order of their
+	/// parameters, so it has to be called before `generate_mock_data()`.
+	/// Reports every error that was found at once.
+	pub fn check_config(&self, config: &mut Config) -> anyhow::Result<()> { // {{{
+		let provider_registry = register_providers()?;
+		let constraint_registry = register_constraints()?;
+		let mut diagnostics = check_names(config);
+		// the links of columns with invalid providers aren't checked, as they
+		// would report the same error again
+		let mut invalid_providers = HashSet::new();
+
+		for (table_index, table) in config.tables.iter_mut().enumerate() {
+			for (column_index, column) in table.columns.iter_mut().enumerate() {
+				if let Err(error) = self.check_provider(&provider_registry, &table.name, column) {
+					diagnostics.push( Diagnostic::new( error, column.provider.location.clone() ) );
+					invalid_providers.insert( (table_index, column_index) );
+				}
+
+				let primary = column.constraints.iter()
+					.any( |constraint| constraint.name == PRIMARY_CONSTRAINT_NAME );
+
+				for constraint in column.constraints.iter_mut() {
+					let callee = format!("{}.{} ${}", table.name, column.name, constraint.name);
 
-		let mut generated_data: HashMap< String, GeneratorData >
-			= HashMap::with_capacity( config.tables.len() );
+					// the constraints are applied in the order they are written,
+					// so a null could either end up in the primary key or abort
+					// the generation
+					if primary && constraint.name == NULL_CONSTRAINT_NAME {
+						let error = ConstraintError::ConflictingConstraints(
+							callee,
+							format!("${}", PRIMARY_CONSTRAINT_NAME),
+							"primary keys can't contain null values".to_string(),
+						);
+
+						diagnostics.push( Diagnostic::new( error, constraint.location.clone() ) );
 
-		for table in &config.tables {
-			let mut columns: GeneratorData
-				= Vec::with_capacity( table.columns.len() );
+						continue;
+					}
+
+					if let Err(error) = self.check_constraint(&constraint_registry, &callee, constraint) {
+						diagnostics.push( Diagnostic::new( error, constraint.location.clone() ) );
+					}
+				}
+			}
+		}
+
+		for (table_index, table) in config.tables.iter().enumerate() {
+			for (column_index, column) in table.columns.iter().enumerate() {
+				if invalid_providers.contains( &(table_index, column_index) ) {
+					continue;
+				}
+
+				if let Err(error) = dependency::check_link(config, table, column) {
+					diagnostics.push( Diagnostic::new( error, column.provider.location.clone() ) );
+				}
+			}
+		}
+
+		// the generation order can only be determined when every link is valid
+		if diagnostics.is_empty() {
+			dependency::resolve_links(config)?;
+
+			// point at the first table or column of a cycle
+			if let Err(error) = dependency::table_order(config) {
+				let location = match &error {
+					DependencyError::Cycle(names) => config.tables.iter()
+						.find( |t| Some(&t.name) == names.first() )
+						.and_then( |t| t.location.clone() ),
+					_ => None,
+				};
+
+				diagnostics.push( Diagnostic::new(error, location) );
+			}
 
-			for column in &table.columns {
-				let mut rows = Vec::with_capacity(self.args.row_count);
-				let provider = provider_registry.get(
-					column.provider.name.clone()
+			for table in &config.tables {
+				if let Err(error) = dependency::column_order(table) {
+					let location = match &error {
+						DependencyError::Cycle(names) => table.columns.iter()
+							.find( |c| names.first() == Some( &format!("{}.{}", table.name, c.name) ) )
+							.and_then( |c| c.location.clone() ),
+						_ => None,
+					};
+
+					diagnostics.push( Diagnostic::new(error, location) );
+				}
+			}
+		}
+
+		if !diagnostics.is_empty() {
+			return Err( anyhow::Error::new( Diagnostics(diagnostics) ) );
+		}
+
+		Ok(())
+	} // }}}
+
+	/// Resolves the arguments of the provider of `column` and creates the
+	/// provider once, so invalid arguments are found before generating.
+	fn check_provider(
+		&self,
+		registry: &ProviderRegistry,
+		table: &str,
+		column: &mut Column,
+	) -> anyhow::Result<()> { // {{{
+		let provider = &mut column.provider;
+		let callee = format!("{}.{} #{}", table, column.name, provider.name);
+		// looking up the parameters only fails for unknown names
+		let parameters = registry.parameters(&provider.name)
+			.map_err( |_| ProviderError::UnknownProvider( callee.clone() ) )?;
+
+		provider.arguments = parameter::resolve_arguments(
+			&callee,
+			parameters,
+			&provider.arguments,
+			&provider.named_arguments,
+		)?;
+		provider.named_arguments.clear();
+
+		// link providers read the values of their target when they are
+		// created, their argument and the type of their target are checked by
+		// `dependency::check_link()`
+		if provider.name == LINK_PROVIDER_NAME {
+			return Ok(());
+		}
+
+		let created = registry.create(
+			&provider.name,
+			&ProviderCreationData {
+				table: table.to_string(),
+				column: column.name.clone(),
+				kind: column.kind.clone(),
+				arguments: provider.arguments.clone(),
+				generated_data: Rc::new( RefCell::new( MockData::new() ) ),
+				seed: self.args.seed,
+			},
+		)?;
+
+		match created.provided_type() {
+			Some(provided) if !column.kind.accepts(&provided) => Err( ProviderError::IncompatibleProvider(
+				callee,
+				provided,
+				column.kind.clone(),
+			).into() ),
+
+			_ => Ok(()),
+		}
+	} // }}}
+
+	/// Resolves the arguments of `constraint` and creates it once, so invalid
+	/// arguments are found before generating.
+	fn check_constraint(
+		&self,
+		registry: &ConstraintRegistry,
+		callee: &str,
+		constraint: &mut Constraint,
+	) -> anyhow::Result<()> { // {{{
+		let parameters = registry.parameters(&constraint.name)
+			.map_err( |_| ConstraintError::UnknownConstraint( callee.to_string() ) )?;
+
+		constraint.arguments = parameter::resolve_arguments(
+			callee,
+			parameters,
+			&constraint.arguments,
+			&constraint.named_arguments,
+		)?;
+		constraint.named_arguments.clear();
+
+		registry.create(
+			&constraint.name,
+			&ConstraintCreationData {
+				name: constraint.name.clone(),
+				arguments: constraint.arguments.clone(),
+				seed: self.args.seed,
+				..ConstraintCreationData::default()
+			},
+		)?;
+
+		Ok(())
+	} // }}}
+
+	pub fn generate_mock_data(&self, config: &mut Config) -> anyhow::Result<MockData> { // {{{
+		dependency::resolve_links(config)?;
+
+		let table_order = dependency::table_order(config)?;
+
+		// shared with the providers, so they can use data that has already been
+		// generated
+		let generated_data = Rc::new( RefCell::new(
+			MockData::with_capacity( config.tables.len() )
+		) );
+
+		let provider_registry = register_providers()?;
+		let constraint_registry = register_constraints()?;
+
+		for table_index in table_order {
+			let table = &config.tables[table_index];
+			let row_count = table.row_count.unwrap_or(self.args.row_count);
+
+			generated_data.borrow_mut().insert(
+				table.name.clone(),
+				Vec::with_capacity( table.columns.len() ),
+			);
+
+			for column_index in dependency::column_order(table)? {
+				let column = &table.columns[column_index];
+				let mut rows = Vec::with_capacity(row_count);
+				let mut provider = provider_registry.create(
+					&column.provider.name,
+					&ProviderCreationData {
+						table: table.name.clone(),
+						column: column.name.clone(),
+						kind: column.kind.clone(),
+						arguments: column.provider.arguments.clone(),
+						generated_data: generated_data.clone(),
+						seed: self.args.seed,
+					},
 				)?;
+				let mut constraints = Vec::with_capacity( column.constraints.len() );
+
+				for constraint in &column.constraints {
+					constraints.push( constraint_registry.create(
+						&constraint.name,
+						&ConstraintCreationData {
+							table: table.name.clone(),
+							column: column.name.clone(),
+							name: constraint.name.clone(),
+							arguments: constraint.arguments.clone(),
+							seed: self.args.seed,
+						},
+					)? );
+				}
+
+				// only nulls set before the values are checked for uniqueness
+				// leave values unused
+				let mut non_null_share = 1.0;
+				let mut requires_unique_values = false;
 
-				provider.reset(&column.provider.arguments)?;
+				for constraint in &constraints {
+					if constraint.requires_unique_values() {
+						requires_unique_values = true;
 
-				for _ in 0..self.args.row_count {
-					let provided_value = provider.provide()?;
+						break;
+					}
+
+					non_null_share *= 1.0 - constraint.null_percentage() / 100.0;
+				}
 
-					if !column.compatible_with_cell_value(&provided_value) {
-						let error = ProviderError::IncompatibleType(
-							column.kind,
-							provided_value,
+				// fail early instead of retrying until the attempts run out. The
+				// amount of nulls is random, so this only catches the columns
+				// that would run out in most runs.
+				if let ( true, Some(distinct_values) ) = ( requires_unique_values, provider.distinct_values() ) {
+					let unique_values = (row_count as f64 * non_null_share).ceil() as usize;
+
+					if distinct_values < unique_values {
+						let error = ProviderError::TooFewDistinctValues(
+							column.provider.name.clone(),
+							format!("{}.{}", table.name, column.name),
+							distinct_values,
+							unique_values,
 						);
 
-						return Err( anyhow::anyhow!(error) );
+						return Err( column_error(error, column) );
 					}
+				}
+
+				for _ in 0..row_count {
+					let mut attempts = 0;
+
+					// ask the provider for new values until all constraints accept
+					// one
+					let value = loop {
+						if attempts >= self.args.max_attempts {
+							let error = ProviderError::UniqueValuesExhausted(
+								column.provider.name.clone(),
+								format!("{}.{}", table.name, column.name),
+								attempts,
+							);
+
+							return Err( column_error(error, column) );
+						}
+
+						attempts += 1;
+
+						let provided_value = provider.provide()
+							.map_err( |e| column_error(e, column) )?;
+
+						if !column.compatible_with_cell_value(&provided_value) {
+							let error = ProviderError::IncompatibleType(
+								column.kind.clone(),
+								provided_value,
+							);
+
+							return Err( column_error(error, column) );
+						}
+
+						let mut value = Some(provided_value);
+
+						for constraint in constraints.iter_mut() {
+							let Some(accepted_value) = value else {
+								break;
+							};
 
-					rows.push(provided_value);
+							value = constraint.apply(accepted_value)
+								.map_err( |e| column_error(e, column) )?;
+						}
+
+						if let Some(value) = value {
+							break value;
+						}
+					};
+
+					rows.push(value);
 				}
 
-				columns.push( ColumnData {
-					name: column.name.clone(),
-					r#type: column.kind,
-					data: rows,
-				} );
+				generated_data.borrow_mut()
+					.get_mut(&table.name)
+					.expect("the table should be added before its columns are generated")
+					.push( ColumnData {
+						name: column.name.clone(),
+						r#type: column.kind.clone(),
+						data: rows,
+						comment: column.comment.clone(),
+					} );
 			}
 
-			generated_data.insert(
-				table.name.clone(),
-				columns,
-			);
+			// the columns are generated in dependency order, but should be
+			// written in the order they are defined in
+			generated_data.borrow_mut()
+				.get_mut(&table.name)
+				.expect("the table should be added before its columns are generated")
+				.sort_by_key( |data| table.columns.iter().position( |c| c.name == data.name ) );
 		}
 
+		let generated_data = generated_data.take();
+
 		debug!("Generated data: {:#?}", generated_data);
 
 		Ok(generated_data)
 	} // }}}
 
-	pub fn write_mock_data(&self, generated_data: MockData) -> anyhow::Result<()> { // {{{
-		let mut generator_registry = register_generators(&self.args)?;
+	pub fn write_mock_data(&self, config: &Config, generated_data: MockData) -> anyhow::Result<()> { // {{{
+		let generator_registry = register_generators()?;
 
-		let output_dir = Path::new(&self.args.output);
+		let output_dir = Path::new(
+			self.args.output.as_ref()
+				.expect("the output folder should be required when generating")
+		);
 		let file_extension = FILE_EXTENSION_MAPPINGS.get(
 			self.args.r#type.as_str(),
 		)
@@ -111,7 +501,19 @@ impl<'a> Mocker<'a> {
 			// file extension
 			.unwrap_or( &self.args.r#type.as_str() )
 			.to_string();
-		let generator = generator_registry.get( self.args.r#type.clone() )?;
+		let mut generator = generator_registry.create(
+			&self.args.r#type,
+			&GeneratorCreationData {
+				csv_delimiter: self.args.csv_delimiter,
+				csv_quote: self.args.csv_quote,
+				json_compact: self.args.json_compact,
+				json_newline_delimited: self.args.json_layout == JSON_LAYOUT_NEWLINE_DELIMITED,
+				xml_root: self.args.xml_root.clone(),
+				xml_row: self.args.xml_row.clone(),
+				xml_attributes: self.args.xml_attributes,
+				tsql_enum_checks: self.args.tsql_enum_checks,
+			},
+		)?;
 
 		for (table, data) in generated_data.into_iter() {
 			let output_file_name = output_dir.join( format!(
@@ -123,10 +525,18 @@ impl<'a> Mocker<'a> {
 			info!("Using file '{}' for table '{}'", output_file_name.display(), table);
 
 			let output_file = fs::File::create( output_file_name.clone() )?;
+			// every column of a table contains a value for every row
+			let row_count = data.first()
+				.map( |column| column.data.len() )
+				.unwrap_or(0);
+			let table_comment = config.tables.iter()
+				.find( |t| t.name == table )
+				.and_then( |t| t.comment.clone() );
 
 			generator.init(
 				table,
-				self.args.row_count,
+				table_comment,
+				row_count,
 				output_file,
 			)?;
 
@@ -138,3 +548,229 @@ impl<'a> Mocker<'a> {
 		Ok(())
 	} // }}}
 }
+
+#[cfg(test)]
+mod tests {
+	use clap::Parser as CliParser;
+	use tempfile::tempdir;
+
+	use crate::{
+		generator::CellValue,
+		parser::config::ColumnType,
+	};
+
+	use super::*;
+
+	/// Creates the arguments of a `mocker check` run, which tests can change
+	fn args() -> Args { // {{{
+		Args::parse_from(["mocker", "check", "."])
+	} // }}}
+
+	/// Parses and checks `content` as a mock file
+	fn check(args: &Args, content: &str) -> anyhow::Result<Config> { // {{{
+		let directory = tempdir()?;
+		let path = directory.path().join("test.mock");
+
+		fs::write(&path, content)?;
+
+		let mocker = Mocker::new(args);
+		let mut config = mocker.parse_config(&path)?;
+
+		mocker.check_config(&mut config)?;
+
+		Ok(config)
+	} // }}}
+
+	/// Returns the messages of the diagnostics in `error`
+	fn messages(error: anyhow::Error) -> Vec<String> { // {{{
+		match error.downcast::<Diagnostics>() {
+			Ok(diagnostics) => diagnostics.0.iter()
+				.map( |diagnostic| diagnostic.error.to_string() )
+				.collect(),
+			Err(error) => vec![ error.to_string() ],
+		}
+	} // }}}
+
+	/// Returns the messages of the errors `check()` found in `content`
+	fn check_errors(content: &str) -> Vec<String> { // {{{
+		messages( check( &args(), content ).expect_err("the config should contain errors") )
+	} // }}}
+
+	/// Returns the messages of the errors found while generating the data of
+	/// `content`, which has to pass `check()`
+	fn generate_errors(args: &Args, content: &str) -> Vec<String> { // {{{
+		let mut config = check(args, content).expect("the config should be valid");
+		let error = Mocker::new(args).generate_mock_data(&mut config)
+			.expect_err("generating should fail");
+
+		messages(error)
+	} // }}}
+
+	#[test]
+	fn test_check_config_should_reject_null_on_primary_keys_in_any_order() { // {{{
+		let expected = vec![ ConstraintError::ConflictingConstraints(
+			"A.id $null".to_string(),
+			"$primary".to_string(),
+			"primary keys can't contain null values".to_string(),
+		).to_string() ];
+
+		assert_eq!( expected, check_errors("table A { id uint $primary() $null(25) #row() }") );
+		assert_eq!( expected, check_errors("table A { id uint $null(25) $primary() #row() }") );
+	} // }}}
+
+	#[test]
+	fn test_check_config_should_return_error_for_duplicate_columns() { // {{{
+		let expected = vec![ ParserError::DuplicateColumn( "A".to_string(), "id".to_string() ).to_string() ];
+
+		assert_eq!( expected, check_errors("table A { id int #number(0, 5), id string #gender() }") );
+	} // }}}
+
+	#[test]
+	fn test_check_config_should_return_error_for_duplicate_tables() { // {{{
+		let result = check_errors("table A { id int #number() }\ntable A { id int #number() }");
+
+		assert_eq!( 1, result.len() );
+		assert!( result[0].starts_with("table 'A' is already defined at "), "{}", result[0] );
+		assert!( result[0].ends_with("test.mock:1:7"), "{}", result[0] );
+	} // }}}
+
+	#[test]
+	fn test_check_config_should_return_error_for_unknown_providers_and_constraints() { // {{{
+		let expected = vec![
+			ProviderError::UnknownProvider( "A.id #nope".to_string() ).to_string(),
+			ConstraintError::UnknownConstraint( "A.id $nope".to_string() ).to_string(),
+		];
+
+		assert_eq!( expected, check_errors("table A { id int $nope() #nope() }") );
+	} // }}}
+
+	#[test]
+	fn test_check_config_should_return_error_for_incompatible_providers() { // {{{
+		let expected = vec![ ProviderError::IncompatibleProvider(
+			"A.id #gender".to_string(),
+			ColumnType::String(usize::MAX),
+			ColumnType::Int,
+		).to_string() ];
+
+		assert_eq!( expected, check_errors("table A { id int #gender() }") );
+	} // }}}
+
+	#[test]
+	fn test_check_config_should_return_error_for_unknown_link_targets() { // {{{
+		let expected = vec![
+			DependencyError::UnknownTable( "B.a_id".to_string(), "C".to_string() ).to_string(),
+			DependencyError::UnknownColumn( "B.b_id".to_string(), "A.nope".to_string() ).to_string(),
+		];
+
+		let result = check_errors("
+			table A { id uint $primary() #row() }
+			table B { a_id uint #link(\"C.id\"), b_id uint #link(\"A.nope\") }");
+
+		assert_eq!(expected, result);
+	} // }}}
+
+	#[test]
+	fn test_check_config_should_return_error_for_links_to_columns_of_another_type() { // {{{
+		let expected = vec![ DependencyError::IncompatibleTarget(
+			"B.a_id".to_string(),
+			"A.id".to_string(),
+			ColumnType::String(usize::MAX),
+			ColumnType::UnsignedInt,
+		).to_string() ];
+
+		let result = check_errors("
+			table A { id uint $primary() #row() }
+			table B { a_id string #link(\"A.id\") }");
+
+		assert_eq!(expected, result);
+	} // }}}
+
+	#[test]
+	fn test_generate_mock_data_should_fail_before_generating_when_too_few_values_are_distinct() { // {{{
+		let mut args = args();
+		args.row_count = 3;
+
+		let expected = vec![
+			ProviderError::TooFewDistinctValues( "random".to_string(), "A.id".to_string(), 2, 3 ).to_string(),
+		];
+
+		assert_eq!( expected, generate_errors(&args, "table A { id int $unique() #random(1, 2) }") );
+	} // }}}
+
+	#[test]
+	fn test_generate_mock_data_should_not_count_the_expected_nulls_as_distinct_values() -> anyhow::Result<()> { // {{{
+		let mut args = args();
+		args.row_count = 10;
+
+		let mut config = check(&args, "table A { v int $null(50) $unique() #number(0, 5) }")?;
+
+		Mocker::new(&args).generate_mock_data(&mut config)?;
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_generate_mock_data_should_count_nulls_set_after_the_unique_check_as_distinct_values() { // {{{
+		let mut args = args();
+		args.row_count = 10;
+
+		let expected = vec![
+			ProviderError::TooFewDistinctValues( "number".to_string(), "A.v".to_string(), 6, 10 ).to_string(),
+		];
+
+		assert_eq!( expected, generate_errors(&args, "table A { v int $unique() $null(50) #number(0, 5) }") );
+	} // }}}
+
+	#[test]
+	fn test_generate_mock_data_should_fail_when_the_attempts_run_out() { // {{{
+		let mut args = args();
+		args.row_count = 3;
+		args.max_attempts = 5;
+
+		// the provider returns the first value every time in tests
+		let expected = vec![
+			ProviderError::UniqueValuesExhausted( "random".to_string(), "A.id".to_string(), 5 ).to_string(),
+		];
+
+		assert_eq!( expected, generate_errors(&args, "table A { id int $unique() #random(1, 2, 3) }") );
+	} // }}}
+
+	#[test]
+	fn test_generate_mock_data_should_give_every_column_its_own_provider() -> anyhow::Result<()> { // {{{
+		let mut args = args();
+		args.row_count = 3;
+
+		let mut config = check(&args, "table A { first uint #row(), second uint #row() }")?;
+		let data = Mocker::new(&args).generate_mock_data(&mut config)?;
+
+		let expected: Vec<CellValue> = (1..=3).map(CellValue::UnsignedInt).collect();
+
+		for column in &data["A"] {
+			assert_eq!(expected, column.data, "{}", column.name);
+		}
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_write_mock_data_should_use_the_row_count_of_the_table() -> anyhow::Result<()> { // {{{
+		let output = tempdir()?;
+		let mut args = args();
+		args.row_count = 2;
+		args.r#type = "csv".to_string();
+		args.output = Some( output.path().display().to_string() );
+
+		let mocker = Mocker::new(&args);
+		let mut config = check(&args, "table Orders(5) { id uint #row() }\ntable Tags { id uint #row() }")?;
+		let data = mocker.generate_mock_data(&mut config)?;
+
+		mocker.write_mock_data(&config, data)?;
+
+		// a header followed by the rows
+		assert_eq!( 6, fs::read_to_string( output.path().join("Orders.csv") )?.lines().count() );
+		assert_eq!( 3, fs::read_to_string( output.path().join("Tags.csv") )?.lines().count() );
+
+		Ok(())
+	} // }}}
+}
+
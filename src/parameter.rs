@@ -0,0 +1,346 @@
+use std::fmt;
+use thiserror::Error;
+
+use crate::parser::config::{ Argument, NamedArgument };
+
+#[derive(Debug, Error)]
+#[cfg_attr( test, derive(PartialEq) )]
+pub enum ParameterError {
+	/// Used when a named argument {0} of {1} doesn't match any of its
+	/// parameters. {2} describes the parameters that can be named
+	#[error("Unknown parameter '{0}' for '{1}', expected {2}")]
+	UnknownParameter(String, String, String),
+
+	/// Used when parameter {0} of {1} is given more than once
+	#[error("Parameter '{0}' of '{1}' is given more than once")]
+	DuplicateArgument(String, String),
+
+	/// Used when {1} positional arguments are given to {0}, which only has {2}
+	/// parameters
+	#[error("'{0}' accepts at most {2} arguments, but {1} were given")]
+	TooManyArguments(String, usize, usize),
+
+	/// Used when parameter {0} of {1} has no default, but a parameter after it
+	/// is given
+	#[error("Parameter '{0}' of '{1}' has to be given, because a parameter after it is given")]
+	MissingArgument(String, String),
+
+	/// Used when the value {2} given for parameter {0} of {1} isn't of type
+	/// {3}
+	#[error("Unexpected value '{2}' for parameter '{0}' of '{1}', expected {3}")]
+	UnexpectedType(String, String, Argument, ParameterKind),
+}
+
+/// The type of the values a parameter accepts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterKind {
+	Int,
+	/// An int or a float
+	Number,
+	String,
+	Boolean,
+	Any,
+}
+
+impl ParameterKind { // {{{
+	pub fn accepts(&self, argument: &Argument) -> bool {
+		matches!(
+			(self, argument),
+			(Self::Int, Argument::Int(_))
+				| (Self::Number, Argument::Int(_) | Argument::Float(_))
+				| (Self::String, Argument::String(_))
+				| (Self::Boolean, Argument::Boolean(_))
+				| (Self::Any, _)
+		)
+	}
+} // }}}
+
+impl fmt::Display for ParameterKind { // {{{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let kind = match self {
+			Self::Int => "int",
+			Self::Number => "int or float",
+			Self::String => "string",
+			Self::Boolean => "boolean",
+			Self::Any => "any value",
+		};
+
+		write!(f, "{}", kind)
+	}
+} // }}}
+
+/// A parameter of a provider or constraint, which can be given as a
+/// positional or as a named argument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parameter {
+	pub name: &'static str,
+	pub kind: ParameterKind,
+	/// The value used when the argument isn't given, but a parameter after it
+	/// is. `None` when the argument has to be given in that case
+	pub default: Option<Argument>,
+	/// Whether the parameter takes all remaining positional arguments. Only the
+	/// last parameter can be variadic
+	pub variadic: bool,
+}
+
+impl Parameter { // {{{
+	pub fn new(name: &'static str, kind: ParameterKind) -> Self {
+		Self {
+			name,
+			kind,
+			default: None,
+			variadic: false,
+		}
+	}
+
+	pub fn with_default(self, default: Argument) -> Self {
+		Self { default: Some(default), ..self }
+	}
+
+	pub fn variadic(self) -> Self {
+		Self { variadic: true, ..self }
+	}
+} // }}}
+
+/// Matches the positional and named arguments given to `callee` with its
+/// parameters. Returns the arguments in the order of the parameters, so they
+/// can be read by position. Parameters that aren't given are filled with their
+/// default when a parameter after them is given, the trailing ones are left
+/// out.
+///
+/// # Arguments
+///
+/// - `callee` The name of the provider or constraint, used in errors
+/// - `parameters` The parameters of the provider or constraint
+/// - `arguments` The positional arguments
+/// - `named_arguments` The arguments given as `name = value`
+pub fn resolve_arguments(
+	callee: &str,
+	parameters: &[Parameter],
+	arguments: &[Argument],
+	named_arguments: &[NamedArgument],
+) -> Result<Vec<Argument>, ParameterError> { // {{{
+	let (fixed, variadic) = match parameters.split_last() {
+		Some( (last, fixed) ) if last.variadic => ( fixed, Some(last) ),
+		_ => (parameters, None),
+	};
+	let mut values: Vec<Option<Argument>> = vec![None; fixed.len()];
+	let mut remaining = Vec::new();
+
+	for (i, argument) in arguments.iter().enumerate() {
+		let parameter = match ( fixed.get(i), variadic ) {
+			( Some(parameter), _ ) => parameter,
+			( None, Some(parameter) ) => parameter,
+			( None, None ) => return Err( ParameterError::TooManyArguments(
+				callee.to_string(),
+				arguments.len(),
+				fixed.len(),
+			) ),
+		};
+
+		check_kind(callee, parameter, argument)?;
+
+		match values.get_mut(i) {
+			Some(value) => *value = Some( argument.clone() ),
+			None => remaining.push( argument.clone() ),
+		}
+	}
+
+	for named_argument in named_arguments {
+		let Some(i) = fixed.iter().position( |p| p.name == named_argument.name ) else {
+			let names = fixed.iter()
+				.map( |p| p.name )
+				.collect::< Vec<&str> >();
+
+			return Err( ParameterError::UnknownParameter(
+				named_argument.name.clone(),
+				callee.to_string(),
+				match names.is_empty() {
+					true => "no named arguments".to_string(),
+					false => format!( "one of: {}", names.join(", ") ),
+				},
+			) );
+		};
+
+		if values[i].is_some() {
+			return Err( ParameterError::DuplicateArgument(
+				named_argument.name.clone(),
+				callee.to_string(),
+			) );
+		}
+
+		check_kind(callee, &fixed[i], &named_argument.value)?;
+
+		values[i] = Some( named_argument.value.clone() );
+	}
+
+	// every fixed parameter has to be filled before the variadic values
+	let given = match remaining.is_empty() {
+		true => values.iter().rposition( |v| v.is_some() ).map_or(0, |i| i + 1),
+		false => values.len(),
+	};
+	let mut resolved = Vec::with_capacity( given + remaining.len() );
+
+	for (parameter, value) in fixed.iter().zip(values).take(given) {
+		let value = value.or_else( || parameter.default.clone() )
+			.ok_or_else( || ParameterError::MissingArgument(
+				parameter.name.to_string(),
+				callee.to_string(),
+			) )?;
+
+		resolved.push(value);
+	}
+
+	resolved.extend(remaining);
+
+	Ok(resolved)
+} // }}}
+
+fn check_kind(callee: &str, parameter: &Parameter, argument: &Argument) -> Result<(), ParameterError> { // {{{
+	if parameter.kind.accepts(argument) {
+		return Ok(());
+	}
+
+	Err( ParameterError::UnexpectedType(
+		parameter.name.to_string(),
+		callee.to_string(),
+		argument.clone(),
+		parameter.kind,
+	) )
+} // }}}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn number_parameters() -> Vec<Parameter> { // {{{
+		vec![
+			Parameter::new("min", ParameterKind::Int).with_default( Argument::Int(0) ),
+			Parameter::new("max", ParameterKind::Int),
+		]
+	} // }}}
+
+	fn named(name: &str, value: Argument) -> NamedArgument { // {{{
+		NamedArgument::new( name.to_string(), value )
+	} // }}}
+
+	#[test]
+	fn test_resolve_arguments_should_order_named_arguments_by_parameter() -> Result<(), ParameterError> { // {{{
+		let result = resolve_arguments(
+			"#number",
+			&number_parameters(),
+			&[],
+			&[ named( "max", Argument::Int(10) ), named( "min", Argument::Int(5) ) ],
+		)?;
+
+		assert_eq!( vec![ Argument::Int(5), Argument::Int(10) ], result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_arguments_should_fill_skipped_parameters_with_their_default() -> Result<(), ParameterError> { // {{{
+		let result = resolve_arguments(
+			"#number",
+			&number_parameters(),
+			&[],
+			&[ named( "max", Argument::Int(10) ) ],
+		)?;
+
+		assert_eq!( vec![ Argument::Int(0), Argument::Int(10) ], result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_arguments_should_leave_out_trailing_parameters() -> Result<(), ParameterError> { // {{{
+		let result = resolve_arguments( "#number", &number_parameters(), &[], &[] )?;
+
+		assert_eq!( Vec::<Argument>::new(), result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_arguments_should_return_error_for_unknown_parameters() -> Result<(), ParameterError> { // {{{
+		let expected = Err( ParameterError::UnknownParameter(
+			"mx".to_string(),
+			"#number".to_string(),
+			"one of: min, max".to_string(),
+		) );
+
+		let result = resolve_arguments(
+			"#number",
+			&number_parameters(),
+			&[],
+			&[ named( "mx", Argument::Int(10) ) ],
+		);
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_arguments_should_return_error_for_duplicate_arguments() -> Result<(), ParameterError> { // {{{
+		let expected = Err( ParameterError::DuplicateArgument(
+			"min".to_string(),
+			"#number".to_string(),
+		) );
+
+		let result = resolve_arguments(
+			"#number",
+			&number_parameters(),
+			&[ Argument::Int(1) ],
+			&[ named( "min", Argument::Int(2) ) ],
+		);
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_arguments_should_return_error_for_wrong_types() -> Result<(), ParameterError> { // {{{
+		let expected = Err( ParameterError::UnexpectedType(
+			"min".to_string(),
+			"#number".to_string(),
+			Argument::Boolean(true),
+			ParameterKind::Int,
+		) );
+
+		let result = resolve_arguments(
+			"#number",
+			&number_parameters(),
+			&[ Argument::Boolean(true) ],
+			&[],
+		);
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_arguments_should_pass_remaining_arguments_to_a_variadic_parameter() -> Result<(), ParameterError> { // {{{
+		let parameters = vec![ Parameter::new("options", ParameterKind::Any).variadic() ];
+		let arguments = vec![ Argument::Int(1), Argument::String( "a".to_string() ) ];
+
+		let result = resolve_arguments("#random", &parameters, &arguments, &[])?;
+
+		assert_eq!(arguments, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_resolve_arguments_should_return_error_for_too_many_arguments() -> Result<(), ParameterError> { // {{{
+		let expected = Err( ParameterError::TooManyArguments( "#row".to_string(), 1, 0 ) );
+
+		let result = resolve_arguments( "#row", &[], &[ Argument::Int(1) ], &[] );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+}
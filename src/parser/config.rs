@@ -1,9 +1,22 @@
-use pest::iterators::Pair;
-use std::fmt;
+use pest::{
+	iterators::Pair,
+	Parser as PestParser,
+};
+use std::{
+	collections::HashMap,
+	fmt,
+	num::IntErrorKind,
+	sync::Arc,
+};
 
-use crate::generator::CellValue;
+use crate::{
+	decimal::MAX_PRECISION,
+	diagnostic::Location,
+	generator::CellValue,
+};
 use super::{
 	errors::ParserError,
+	MockerParser,
 	Rule,
 };
 
@@ -21,10 +34,16 @@ impl Argument {
 		let value = rule.as_span()
 			.as_str();
 		let value = value.parse()
-			.map_err( |_| ParserError::Unexpected(
-				value.to_string(),
-				"int".to_string(),
-			) )?;
+			.map_err( |e: std::num::ParseIntError| match e.kind() {
+				IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => ParserError::OutOfRange(
+					value.to_string(),
+					format!("an int between {} and {}", i64::MIN, i64::MAX),
+				),
+				_ => ParserError::Unexpected(
+					value.to_string(),
+					"int".to_string(),
+				),
+			} )?;
 
 		Ok( Self::Int(value) )
 	}
@@ -32,19 +51,50 @@ impl Argument {
 	fn try_from_float_rule(rule: Pair<Rule>) -> Result<Self, ParserError> {
 		let value = rule.as_span()
 			.as_str();
-		let value = value.parse()
+		let parsed: f64 = value.parse()
 			.map_err( |_| ParserError::Unexpected(
 				value.to_string(),
 				"float".to_string(),
 			) )?;
 
-		Ok( Self::Float(value) )
+		// too large floats are parsed as infinity instead of returning an error
+		if !parsed.is_finite() {
+			return Err( ParserError::OutOfRange(
+				value.to_string(),
+				format!("a float between {:e} and {:e}", f64::MIN, f64::MAX),
+			) );
+		}
+
+		Ok( Self::Float(parsed) )
 	}
 
 	fn try_from_string_rule(rule: Pair<Rule>) -> Result<Self, ParserError> {
-		let value = rule.as_span()
-			.as_str()
-			.to_string();
+		// the STRING rule includes the quotes, the content is in its inner rule
+		let content = rule.into_inner()
+			.next()
+			.expect("string should contain string content")
+			.as_str();
+		let mut value = String::with_capacity( content.len() );
+		let mut chars = content.chars();
+
+		while let Some(c) = chars.next() {
+			if c != '\\' {
+				value.push(c);
+
+				continue;
+			}
+
+			// the grammar only allows valid escape sequences
+			match chars.next() {
+				Some('b') => value.push('\u{08}'),
+				Some('f') => value.push('\u{0C}'),
+				Some('n') => value.push('\n'),
+				Some('r') => value.push('\r'),
+				Some('t') => value.push('\t'),
+				Some(c) => value.push(c),
+				None => {},
+			}
+		}
 
 		Ok( Self::String(value) )
 	}
@@ -63,6 +113,23 @@ impl Argument {
 	// }}}
 }
 
+impl Argument { // {{{
+	/// Parses the value of a constant given on the command line. Values that
+	/// aren't a number, boolean or quoted string are used as a string, so
+	/// `--define START=2020-01-01` doesn't need quotes.
+	pub fn from_define(text: &str) -> Result<Self, ParserError> {
+		let Ok(mut pairs) = MockerParser::parse(Rule::define_value, text) else {
+			return Ok( Self::String( text.to_string() ) );
+		};
+
+		let value = pairs.next()
+			.and_then( |define_value| define_value.into_inner().next() )
+			.expect("define_value should contain a value");
+
+		Self::try_from(value)
+	}
+} // }}}
+
 impl TryFrom< Pair<'_, Rule> > for Argument { // {{{
 	type Error = ParserError;
 
@@ -88,7 +155,7 @@ impl fmt::Display for Argument { // {{{
 		let value: String = match self {
 			Self::Int(i) => format!("{}", i),
 			Self::Float(f) => format!("{}", f),
-			Self::String(s) => format!("{}", s),
+			Self::String(s) => s.to_string(),
 			Self::Boolean(b) => format!("{}", b),
 		};
 
@@ -96,22 +163,74 @@ impl fmt::Display for Argument { // {{{
 	}
 } // }}}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A variant of an enum declared with `enum Name { Variant(weight) }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnumVariant {
+	pub name: String,
+	/// How often the variant is generated compared to the other variants of
+	/// its enum. Variants with a weight of 0 are valid, but never generated
+	pub weight: u32,
+}
+
+impl EnumVariant { // {{{
+	pub fn new(name: String, weight: u32) -> Self {
+		Self { name, weight }
+	}
+} // }}}
+
+/// An enum declared in the config, which can be used as the type of a column.
+/// Its values are the names of its variants.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct EnumType {
+	pub name: String,
+	pub variants: Vec<EnumVariant>,
+}
+
+impl EnumType { // {{{
+	pub fn new(name: String, variants: Vec<EnumVariant>) -> Self {
+		Self { name, variants }
+	}
+
+	/// Returns whether `value` is the name of one of the variants
+	pub fn contains(&self, value: &str) -> bool {
+		self.variants.iter()
+			.any( |variant| variant.name == value )
+	}
+} // }}}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ColumnType {
 	Int,
 	UnsignedInt,
 	Float,
 	Boolean,
-	/// String type with a max length of {0}
+	/// String type with a max length of {0}. `usize::MAX` when the length
+	/// isn't limited
 	String(usize),
+	/// Exact number with {0} digits, of which {1} are after the decimal point
+	Decimal(u32, u32),
+	Date,
+	Time,
+	DateTime,
+	/// One of the variants of an enum declared in the config, written as a
+	/// string
+	Enum( Arc<EnumType> ),
 }
 
+/// The precision of a decimal column when none is given, matching most
+/// databases
+const DEFAULT_DECIMAL_PRECISION: u32 = 18;
+
 // ColumnType static text strings {{{
-pub const KEY_COLUMN_TYPE_INT: &'static str = "int";
-pub const KEY_COLUMN_TYPE_UNSIGNED_INT: &'static str = "uint";
-pub const KEY_COLUMN_TYPE_FLOAT: &'static str = "float";
-pub const KEY_COLUMN_TYPE_BOOLEAN: &'static str = "bool";
-pub const KEY_COLUMN_TYPE_STRING: &'static str = "string";
+pub const KEY_COLUMN_TYPE_INT: &str = "int";
+pub const KEY_COLUMN_TYPE_UNSIGNED_INT: &str = "uint";
+pub const KEY_COLUMN_TYPE_FLOAT: &str = "float";
+pub const KEY_COLUMN_TYPE_BOOLEAN: &str = "bool";
+pub const KEY_COLUMN_TYPE_STRING: &str = "string";
+pub const KEY_COLUMN_TYPE_DECIMAL: &str = "decimal";
+pub const KEY_COLUMN_TYPE_DATE: &str = "date";
+pub const KEY_COLUMN_TYPE_TIME: &str = "time";
+pub const KEY_COLUMN_TYPE_DATE_TIME: &str = "date_time";
 // }}}
 
 impl TryFrom<String> for ColumnType { // {{{
@@ -123,14 +242,96 @@ impl TryFrom<String> for ColumnType { // {{{
 			KEY_COLUMN_TYPE_UNSIGNED_INT => Ok(ColumnType::UnsignedInt),
 			KEY_COLUMN_TYPE_FLOAT => Ok(ColumnType::Float),
 			KEY_COLUMN_TYPE_BOOLEAN => Ok(ColumnType::Boolean),
-			// TODO: Make string length variable
 			KEY_COLUMN_TYPE_STRING => Ok( ColumnType::String(usize::MAX) ),
+			KEY_COLUMN_TYPE_DECIMAL => Ok( ColumnType::Decimal(DEFAULT_DECIMAL_PRECISION, 0) ),
+			KEY_COLUMN_TYPE_DATE => Ok(ColumnType::Date),
+			KEY_COLUMN_TYPE_TIME => Ok(ColumnType::Time),
+			KEY_COLUMN_TYPE_DATE_TIME => Ok(ColumnType::DateTime),
 
 			_ => Err( ParserError::Unexpected( kind, "Type".to_string() ) ),
 		}
 	}
 } // }}}
 
+impl ColumnType { // {{{
+	/// Applies the arguments given to the type in the config, like the length
+	/// in `string(128)`.
+	pub fn with_arguments(self, arguments: &[usize]) -> Result<Self, ParserError> {
+		match (&self, arguments) {
+			(_, []) => Ok(self),
+
+			( Self::String(_), [0] ) => Err( ParserError::Unexpected(
+				"0".to_string(),
+				"a length of at least 1".to_string(),
+			) ),
+			( Self::String(_), [max_length] ) => Ok( Self::String(*max_length) ),
+
+			( Self::Decimal(..), [precision] ) => Self::decimal(*precision, 0),
+			( Self::Decimal(..), [precision, scale] ) => Self::decimal(*precision, *scale),
+
+			_ => Err( ParserError::Unexpected(
+				format!(
+					"{}({})",
+					self,
+					arguments.iter()
+						.map( |argument| argument.to_string() )
+						.collect::< Vec<String> >()
+						.join(", "),
+				),
+				"Type".to_string(),
+			) ),
+		}
+	}
+
+	fn decimal(precision: usize, scale: usize) -> Result<Self, ParserError> {
+		let max_precision = MAX_PRECISION as usize;
+
+		if !(1..=max_precision).contains(&precision) {
+			return Err( ParserError::Unexpected(
+				precision.to_string(),
+				format!("a precision between 1 and {}", max_precision),
+			) );
+		}
+
+		if scale > precision {
+			return Err( ParserError::Unexpected(
+				scale.to_string(),
+				format!("a scale of at most the precision ({})", precision),
+			) );
+		}
+
+		// both are at most MAX_PRECISION, so they fit
+		Ok( Self::Decimal(precision as u32, scale as u32) )
+	}
+
+	/// Returns the maximum amount of characters a value of this type can
+	/// contain, or `None` when there is no limit.
+	pub fn max_length(&self) -> Option<usize> {
+		match self {
+			Self::String(max_length) if *max_length != usize::MAX => Some(*max_length),
+
+			_ => None,
+		}
+	}
+
+	/// Returns whether every value of type `provided` fits in a column of this
+	/// type. Strings are accepted regardless of their length, because
+	/// providers shorten them to the maximum length of the column.
+	pub fn accepts(&self, provided: &ColumnType) -> bool {
+		match (self, provided) {
+			( Self::Float, Self::Int | Self::Float | Self::Decimal(_, _) ) => true,
+			( Self::String(_), Self::String(_) ) => true,
+			( Self::Decimal(precision, scale), Self::Decimal(provided_precision, provided_scale) ) =>
+				provided_scale <= scale && provided_precision - provided_scale <= precision - scale,
+			// enums are written as strings, whether a string is one of its
+			// variants is checked while generating
+			( Self::Enum(_), Self::String(_) ) => true,
+
+			(kind, provided) => kind == provided,
+		}
+	}
+} // }}}
+
 impl std::fmt::Display for ColumnType { // {{{
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
 		macro_rules! write_key {
@@ -144,41 +345,86 @@ impl std::fmt::Display for ColumnType { // {{{
 			Self::UnsignedInt => write_key!(KEY_COLUMN_TYPE_UNSIGNED_INT),
 			Self::Float => write_key!(KEY_COLUMN_TYPE_FLOAT),
 			Self::Boolean => write_key!(KEY_COLUMN_TYPE_BOOLEAN),
+			Self::String(usize::MAX) => write_key!(KEY_COLUMN_TYPE_STRING),
 			Self::String(max_length) => write!(f, "{}({})", KEY_COLUMN_TYPE_STRING, max_length),
+			Self::Decimal(precision, scale) => write!(f, "{}({}, {})", KEY_COLUMN_TYPE_DECIMAL, precision, scale),
+			Self::Date => write_key!(KEY_COLUMN_TYPE_DATE),
+			Self::Time => write_key!(KEY_COLUMN_TYPE_TIME),
+			Self::DateTime => write_key!(KEY_COLUMN_TYPE_DATE_TIME),
+			Self::Enum(enum_type) => write_key!(enum_type.name),
 		}
 	}
 } // }}}
 
+/// An argument given as `name = value`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedArgument {
+	pub name: String,
+	pub value: Argument,
+}
+
+impl NamedArgument { // {{{
+	pub fn new(name: String, value: Argument) -> Self {
+		Self { name, value }
+	}
+} // }}}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Constraint {
 	pub name: String,
+	/// The positional arguments. After the arguments are resolved, this
+	/// contains all arguments in the order of the parameters of the constraint
 	pub arguments: Vec<Argument>,
+	/// The arguments given as `name = value`. Empty after the arguments are
+	/// resolved
+	pub named_arguments: Vec<NamedArgument>,
+	/// Where the call is written in the config
+	pub location: Option<Location>,
 }
 
 impl Constraint { // {{{
-	pub fn new(name: String, arguments: Vec<Argument>) -> Self {
-		Self { name, arguments }
+	pub fn new(name: String, arguments: Vec<Argument>, named_arguments: Vec<NamedArgument>) -> Self {
+		Self { name, arguments, named_arguments, location: None }
+	}
+
+	pub fn with_location(self, location: Location) -> Self {
+		Self { location: Some(location), ..self }
 	}
 } // }}}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Provider {
 	pub name: String,
+	/// The positional arguments. After the arguments are resolved, this
+	/// contains all arguments in the order of the parameters of the provider
 	pub arguments: Vec<Argument>,
+	/// The arguments given as `name = value`. Empty after the arguments are
+	/// resolved
+	pub named_arguments: Vec<NamedArgument>,
+	/// Where the call is written in the config
+	pub location: Option<Location>,
 }
 
 impl Provider { // {{{
-	pub fn new(name: String, arguments: Vec<Argument>) -> Self {
-		Self { name, arguments }
+	pub fn new(name: String, arguments: Vec<Argument>, named_arguments: Vec<NamedArgument>) -> Self {
+		Self { name, arguments, named_arguments, location: None }
+	}
+
+	pub fn with_location(self, location: Location) -> Self {
+		Self { location: Some(location), ..self }
 	}
 } // }}}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Column {
 	pub name: String,
 	pub kind: ColumnType,
 	pub constraints: Vec<Constraint>,
 	pub provider: Provider,
+	/// The text of the `///` doc comments above the column
+	pub comment: Option<String>,
+	/// Where the name of the column is written in the config
+	pub location: Option<Location>,
 }
 
 impl Column { // {{{
@@ -188,19 +434,36 @@ impl Column { // {{{
 		constraints: Vec<Constraint>,
 		provider: Provider,
 	) -> Column {
-		Self { name, kind, constraints, provider }
+		Self { name, kind, constraints, provider, comment: None, location: None }
+	}
+
+	pub fn with_comment(self, comment: Option<String>) -> Column {
+		Self { comment, ..self }
+	}
+
+	pub fn with_location(self, location: Location) -> Column {
+		Self { location: Some(location), ..self }
 	}
 
 	pub fn compatible_with_cell_value(&self, cell_value: &CellValue) -> bool {
 		// Move to https://github.com/rust-lang/rust/issues/51114 ?
-		match (self.kind, cell_value) {
+		match (&self.kind, cell_value) {
 			( ColumnType::Int, CellValue::Int(_) ) => true,
 			( ColumnType::UnsignedInt, CellValue::UnsignedInt(_) ) => true,
-			( ColumnType::Float, CellValue::Int(_) ) => true,
+			( ColumnType::Float, CellValue::Int(_) | CellValue::Float(_) | CellValue::Decimal(_) ) => true,
+			( ColumnType::Decimal(precision, scale), CellValue::Decimal(value) )
+				if value.scale() <= *scale && value.integer_digits() <= precision - scale => true,
 			( ColumnType::Boolean, CellValue::Boolean(_) ) => true,
+			( ColumnType::Date, CellValue::Date(_) ) => true,
+			( ColumnType::Time, CellValue::Time(_) ) => true,
+			( ColumnType::DateTime, CellValue::DateTime(_) ) => true,
+
+			// every column can be empty
+			( _, CellValue::Null ) => true,
 
 			( ColumnType::String(max_length), CellValue::String(value) )
-				if value.len() <= max_length => true,
+				if value.chars().count() <= *max_length => true,
+			( ColumnType::Enum(enum_type), CellValue::String(value) ) => enum_type.contains(value),
 
 			_ => false,
 		}
@@ -211,12 +474,27 @@ impl Column { // {{{
 #[derive(Debug)]
 pub struct Table {
 	pub name: String,
+	/// The amount of rows to generate for this table. Uses the amount given on
+	/// the command line when `None`
+	pub row_count: Option<usize>,
 	pub columns: Vec<Column>,
+	/// The text of the `///` doc comments above the table
+	pub comment: Option<String>,
+	/// Where the name of the table is written in the config
+	pub location: Option<Location>,
 }
 
 impl Table { // {{{
-	pub fn new(name: String, columns: Vec<Column>) -> Table {
-		Self { name, columns }
+	pub fn new(name: String, row_count: Option<usize>, columns: Vec<Column>) -> Table {
+		Self { name, row_count, columns, comment: None, location: None }
+	}
+
+	pub fn with_comment(self, comment: Option<String>) -> Table {
+		Self { comment, ..self }
+	}
+
+	pub fn with_location(self, location: Location) -> Table {
+		Self { location: Some(location), ..self }
 	}
 }
 // }}}
@@ -224,17 +502,179 @@ impl Table { // {{{
 #[derive(Debug)]
 pub struct Config {
 	pub tables: Vec<Table>,
+	/// Groups of columns that tables can take over using `table Name :
+	/// Template`. Their columns are already added to the tables using them,
+	/// no data is generated for the templates themselves
+	pub templates: Vec<Table>,
+	/// The values of the constants declared with `let NAME = value;`. They
+	/// are already replaced by their values in the arguments
+	pub constants: HashMap<String, Argument>,
+	/// The enums declared with `enum Name { Variant }`, which can be used as
+	/// column types
+	pub enums: Vec< Arc<EnumType> >,
 }
 
 impl Config { // {{{
 	pub fn new() -> Config {
 		Config {
 			tables: Vec::with_capacity(5),
+			templates: Vec::new(),
+			constants: HashMap::new(),
+			enums: Vec::new(),
 		}
 	}
 
 	pub fn add_table(&mut self, table: Table) {
 		self.tables.push(table);
 	}
+
+	pub fn add_template(&mut self, template: Table) {
+		self.templates.push(template);
+	}
 }
 // }}}
+
+#[cfg(test)]
+mod tests {
+	use pest::Parser as PestParser;
+
+	use super::*;
+	use crate::parser::MockerParser;
+
+	fn parse_argument(text: &str) -> Result<Argument, ParserError> { // {{{
+		let call_arg = MockerParser::parse(Rule::call_arg, text)
+			.map_err( |e| ParserError::SyntaxError( e.to_string() ) )?
+			.next()
+			.expect("call_arg should be parsed")
+			.into_inner()
+			.next()
+			.expect("call_arg should contain an argument value");
+
+		Argument::try_from(call_arg)
+	} // }}}
+
+	#[test]
+	fn test_try_from_should_parse_signed_numbers() -> Result<(), ParserError> { // {{{
+		assert_eq!( Argument::Int(-100), parse_argument("-100")? );
+		assert_eq!( Argument::Int(100), parse_argument("+100")? );
+		assert_eq!( Argument::Float(-0.5), parse_argument("-0.5")? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_try_from_should_parse_the_full_int_range() -> Result<(), ParserError> { // {{{
+		assert_eq!( Argument::Int(i64::MIN), parse_argument("-9223372036854775808")? );
+		assert_eq!( Argument::Int(i64::MAX), parse_argument("9223372036854775807")? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_try_from_should_parse_exponents_as_floats() -> Result<(), ParserError> { // {{{
+		assert_eq!( Argument::Float(1500.0), parse_argument("1.5e3")? );
+		assert_eq!( Argument::Float(-0.015), parse_argument("-1.5E-2")? );
+		assert_eq!( Argument::Float(100000.0), parse_argument("1e5")? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_try_from_should_return_error_when_an_int_overflows() -> Result<(), ParserError> { // {{{
+		let result = parse_argument("9223372036854775808");
+
+		assert!( matches!(
+			result,
+			Err( ParserError::OutOfRange(value, _) ) if value == "9223372036854775808",
+		) );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_try_from_should_return_error_when_a_float_overflows() -> Result<(), ParserError> { // {{{
+		let result = parse_argument("-1e400");
+
+		assert!( matches!(
+			result,
+			Err( ParserError::OutOfRange(value, _) ) if value == "-1e400",
+		) );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_with_arguments_should_set_the_max_length_of_strings() -> Result<(), ParserError> { // {{{
+		let result = ColumnType::try_from( KEY_COLUMN_TYPE_STRING.to_string() )?
+			.with_arguments(&[128])?;
+
+		assert_eq!( ColumnType::String(128), result );
+		assert_eq!( Some(128), result.max_length() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_with_arguments_should_keep_strings_unlimited_without_arguments() -> Result<(), ParserError> { // {{{
+		let result = ColumnType::try_from( KEY_COLUMN_TYPE_STRING.to_string() )?
+			.with_arguments(&[])?;
+
+		assert_eq!( None, result.max_length() );
+		assert_eq!( "string", result.to_string() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_with_arguments_should_return_error_for_types_without_arguments() -> Result<(), ParserError> { // {{{
+		let result = ColumnType::Int.with_arguments(&[5]);
+
+		assert!( matches!(
+			result,
+			Err( ParserError::Unexpected(value, _) ) if value == "int(5)",
+		) );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_compatible_with_cell_value_should_count_characters() -> Result<(), ParserError> { // {{{
+		let column = Column::new(
+			"name".to_string(),
+			ColumnType::String(2),
+			vec![],
+			Provider::new( "random".to_string(), vec![], vec![] ),
+		);
+
+		assert!( column.compatible_with_cell_value( &CellValue::String( "äö".to_string() ) ) );
+		assert!( !column.compatible_with_cell_value( &CellValue::String( "äöü".to_string() ) ) );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_accepts_should_allow_narrower_decimals() { // {{{
+		let sut = ColumnType::Decimal(5, 2);
+
+		assert!( sut.accepts( &ColumnType::Decimal(4, 1) ) );
+		assert!( !sut.accepts( &ColumnType::Decimal(5, 3) ) );
+		assert!( !sut.accepts( &ColumnType::Decimal(6, 2) ) );
+	} // }}}
+
+	#[test]
+	fn test_accepts_should_allow_strings_of_any_length() { // {{{
+		assert!( ColumnType::String(10).accepts( &ColumnType::String(usize::MAX) ) );
+		assert!( ColumnType::Float.accepts(&ColumnType::Int) );
+		assert!( !ColumnType::UnsignedInt.accepts(&ColumnType::Int) );
+	} // }}}
+
+	#[test]
+	fn test_from_define_should_use_unquoted_text_as_string() -> Result<(), ParserError> { // {{{
+		assert_eq!( Argument::Int(100), Argument::from_define("100")? );
+		assert_eq!( Argument::Boolean(true), Argument::from_define("true")? );
+		assert_eq!( Argument::String( "a b".to_string() ), Argument::from_define("\"a b\"")? );
+		assert_eq!( Argument::String( "2020-01-01".to_string() ), Argument::from_define("2020-01-01")? );
+
+		Ok(())
+	} // }}}
+}
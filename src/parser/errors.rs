@@ -2,16 +2,64 @@ use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum ParserError {
-	/// Syntax error
-	#[error("syntax error:\n{0}")]
+	/// Syntax error, `{0}` describes what was expected
+	#[error("syntax error, {0}")]
 	SyntaxError(String),
-	/// ???
-	#[error("")]
-	FileError(String),
+	/// Used when the config file `{0}` can't be read because of `{1}`
+	#[error("could not read '{0}': {1}")]
+	FileError(String, String),
 	/// Unexpected End Of File when parsing config
 	#[error("unexpected Eof Of File")]
-	EOF,
+	Eof,
 	/// Unexpected `{0}`, expected `{1}`
 	#[error("unexpected '{0}', expected {1}")]
 	Unexpected(String, String),
+	/// Number `{0}` doesn't fit in `{1}`
+	#[error("'{0}' is out of range, expected {1}")]
+	OutOfRange(String, String),
+	/// Table `{0}` is defined more than once, `{1}` is where it's defined
+	/// first, which can be in another file
+	#[error("table '{0}' is already defined at {1}")]
+	DuplicateTable(String, String),
+	/// Column `{1}` is defined more than once in table `{0}`
+	#[error("column '{1}' is defined more than once in table '{0}'")]
+	DuplicateColumn(String, String),
+	/// Template `{0}` is defined more than once, `{1}` is where it's defined
+	/// first
+	#[error("template '{0}' is already defined at {1}")]
+	DuplicateTemplate(String, String),
+	/// Template `{0}` isn't defined in the file or the files it imports
+	#[error("unknown template '{0}', templates have to be defined or imported before they are used")]
+	UnknownTemplate(String),
+	/// Table `{0}` has no columns of its own and no templates adding any
+	#[error("table '{0}' has no columns")]
+	EmptyTable(String),
+	/// Constant `{0}` is used, but isn't declared before it
+	#[error("unknown constant '{0}', constants have to be declared or imported before they are used")]
+	UnknownConstant(String),
+	/// Constant `{0}` is declared more than once
+	#[error("constant '{0}' is declared more than once")]
+	DuplicateConstant(String),
+	/// Constant `{0}` is given with `--define`, but isn't declared in the
+	/// config
+	#[error("constant '{0}' is defined on the command line, but isn't declared in the config")]
+	UnknownDefine(String),
+	/// Type `{0}` isn't a built in type or an enum declared before it's used
+	#[error("unknown type '{0}', enums have to be declared or imported before they are used")]
+	UnknownEnum(String),
+	/// Enum `{0}` is declared more than once
+	#[error("enum '{0}' is declared more than once")]
+	DuplicateEnum(String),
+	/// Variant `{1}` of enum `{0}` is declared more than once
+	#[error("variant '{1}' of enum '{0}' is declared more than once")]
+	DuplicateEnumVariant(String, String),
+	/// Every variant of enum `{0}` has a weight of 0, so none can be generated
+	#[error("every variant of enum '{0}' has a weight of 0, at least one has to be generated")]
+	ZeroEnumWeights(String),
+	/// Column `{0}` has no provider, and its type has no default one
+	#[error("column '{0}' needs a provider, only columns of an enum type have a default one")]
+	MissingProvider(String),
+	/// A file imports itself through the files in `{0}`
+	#[error("import cycle: {0}")]
+	ImportCycle(String),
 }
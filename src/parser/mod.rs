@@ -1,20 +1,42 @@
-use std::{ fs, path::PathBuf };
+use std::{
+	collections::{
+		HashMap,
+		HashSet,
+	},
+	fs,
+	path::{ Path, PathBuf },
+	sync::Arc,
+};
 use pest::{
+	error::{ ErrorVariant, InputLocation },
 	iterators::Pair,
 	Parser as PestParser,
+	Span,
 };
 use pest_derive::Parser as PestParser;
 
-use crate::parser::{
-	errors::ParserError,
-	config::{
-		Argument,
-		Config,
-		Column,
-		Provider,
-		ColumnType,
-		Constraint,
-		Table,
+use crate::{
+	providers::enumeration::ENUM_PROVIDER_NAME,
+	diagnostic::{
+		Diagnostic,
+		Diagnostics,
+		Location,
+		Source,
+	},
+	parser::{
+		errors::ParserError,
+		config::{
+			Argument,
+			Config,
+			Column,
+			Provider,
+			ColumnType,
+			Constraint,
+			EnumType,
+			EnumVariant,
+			NamedArgument,
+			Table,
+		},
 	},
 };
 
@@ -25,34 +47,135 @@ pub mod errors;
 #[grammar = "parser/mock_table_definition.pest"]
 struct MockerParser;
 
+/// Keeps track of the files that have been parsed, so a file imported more
+/// than once is only parsed once and import cycles are found.
+#[derive(Default)]
+struct Imports {
+	/// The canonical paths of every file that has been parsed
+	parsed: HashSet<PathBuf>,
+	/// The canonical and the given paths of the files that are being parsed,
+	/// each importing the one after it
+	stack: Vec<(PathBuf, PathBuf)>,
+}
+
+/// Returns the absolute path of `path` with all links resolved, so the same
+/// file imported using different paths is only parsed once. Paths that
+/// can't be resolved are returned as they are.
+fn canonical_path(path: &Path) -> PathBuf { // {{{
+	fs::canonicalize(path)
+		.unwrap_or_else( |_| path.to_path_buf() )
+} // }}}
+
+/// Returns the message of a syntax error. Doc comments are left out of the
+/// expected rules, as they can be placed before most rules and would otherwise
+/// be reported instead of what's actually missing.
+fn syntax_error_message(variant: ErrorVariant<Rule>) -> String { // {{{
+	match variant {
+		ErrorVariant::ParsingError { positives, negatives } => ErrorVariant::ParsingError {
+			positives: positives.into_iter()
+				.filter( |rule| *rule != Rule::doc_comment )
+				.collect(),
+			negatives,
+		}.message().to_string(),
+		variant => variant.message().to_string(),
+	}
+} // }}}
+
 pub struct Parser {
-	file_content: String,
+	source: Arc<Source>,
+	/// The values of constants given on the command line, which replace the
+	/// values they are declared with
+	defines: Arc< HashMap<String, Argument> >,
 }
 
 impl Parser {
 	pub fn new(file: &PathBuf) -> Result<Parser, ParserError> { // {{{
 		let file_content = fs::read_to_string(file)
-			.map_err( |e| ParserError::FileError( e.to_string() ) )?;
+			.map_err( |e| ParserError::FileError(
+				file.display().to_string(),
+				e.to_string(),
+			) )?;
+
+		Ok( Parser {
+			source: Arc::new( Source::new( file.clone(), file_content ) ),
+			defines: Arc::new( HashMap::new() ),
+		} )
+	} // }}}
+
+	pub fn with_defines(self, defines: HashMap<String, Argument>) -> Self { // {{{
+		Self { defines: Arc::new(defines), ..self }
+	} // }}}
+
+	fn location(&self, span: Span) -> Location { // {{{
+		Location::from_span(&self.source, span)
+	} // }}}
+
+	/// Creates a diagnostic for an error found in the text of `span`
+	fn diagnostic(&self, error: ParserError, span: Span) -> Diagnostic { // {{{
+		Diagnostic::new( error, Some( self.location(span) ) )
+	} // }}}
+
+	/// Parses a literal argument or looks up the value of a constant
+	fn parse_call_arg(&self, call_arg: Pair<Rule>, config: &Config)
+		-> Result<Argument, Diagnostic>
+	{ // {{{
+		let span = call_arg.as_span();
+		let arg_rule = call_arg.into_inner()
+			.next()
+			.expect("function call argument should contain an argument value");
 
-		Ok(Parser { file_content })
+		if arg_rule.as_rule() != Rule::constant_name {
+			return Argument::try_from(arg_rule)
+				.map_err( |e| self.diagnostic(e, span) );
+		}
+
+		config.constants.get( arg_rule.as_str() )
+			.cloned()
+			.ok_or_else( || self.diagnostic(
+				ParserError::UnknownConstant( arg_rule.as_str().to_string() ),
+				span,
+			) )
 	} // }}}
 
 	fn parse_function_call_args(
 		&self,
 		function_call_args: Pair<Rule>,
+		config: &Config,
 		args: &mut Vec<Argument>,
+		named_args: &mut Vec<NamedArgument>,
 	)
-		-> Result<(), ParserError>
+		-> Result<(), Diagnostic>
 	{ // {{{
-			// Argument::try_from(function_call_item)
 		for argument in function_call_args.into_inner() {
+			let span = argument.as_span();
+
 			match argument.as_rule() {
 				Rule::call_arg => {
-					let arg_rule = argument.into_inner()
-						.next()
-						.expect("function call argument should contain an argument value");
+					// keep the positions of the positional arguments unambiguous
+					if !named_args.is_empty() {
+						return Err( self.diagnostic(
+							ParserError::Unexpected(
+								argument.as_str().to_string(),
+								"a named argument, positional arguments have to come first".to_string(),
+							),
+							span,
+						) );
+					}
+
+					args.push( self.parse_call_arg(argument, config)? );
+				},
+				Rule::named_call_arg => {
+					let mut named_call_arg_items = argument.into_inner();
+					let name = named_call_arg_items.next()
+						.expect("named argument should contain a name")
+						.as_str()
+						.to_string();
+					let call_arg = named_call_arg_items.next()
+						.expect("named argument should contain an argument");
+
+					let value = self.parse_call_arg(call_arg, config)?;
 
-					args.push( Argument::try_from(arg_rule)? );
+					named_args.push( NamedArgument::new(name, value) );
 				},
 
 				r => unreachable!("Unexpected rule encountered while parsing function_call_args: {:?}", r),
@@ -65,13 +188,15 @@ impl Parser {
 	fn parse_function_call(
 		&self,
 		function_call: Pair<Rule>,
+		config: &Config,
 		args: &mut Vec<Argument>,
-	) -> Result<(), ParserError> { // {{{
+		named_args: &mut Vec<NamedArgument>,
+	) -> Result<(), Diagnostic> { // {{{
 		for function_call_item in function_call.into_inner() {
 			match function_call_item.as_rule() {
 				Rule::function_call_empty => {}, // no arguments to parse
 				Rule::function_call_args =>
-					self.parse_function_call_args(function_call_item, args)?,
+					self.parse_function_call_args(function_call_item, config, args, named_args)?,
 
 				r => unreachable!("Unexpected rule encountered while parsing function_call_item: {:?}", r),
 			}
@@ -80,11 +205,13 @@ impl Parser {
 		Ok(())
 	} // }}}
 
-	fn parse_constraint(&self, constraint: Pair<Rule>)
-		-> Result<Constraint, ParserError>
+	fn parse_constraint(&self, constraint: Pair<Rule>, config: &Config)
+		-> Result<Constraint, Diagnostic>
 	{ // {{{
+		let location = self.location( constraint.as_span() );
 		let mut constraint_name: Option<String> = None;
 		let mut constraint_args = Vec::with_capacity(2);
+		let mut constraint_named_args = Vec::new();
 
 		for constraint_item in constraint.into_inner() {
 			match constraint_item.as_rule() {
@@ -96,7 +223,7 @@ impl Parser {
 					constraint_name = Some(name);
 				},
 				Rule::function_call =>
-					self.parse_function_call(constraint_item, &mut constraint_args)?,
+					self.parse_function_call(constraint_item, config, &mut constraint_args, &mut constraint_named_args)?,
 
 				r => unreachable!("Unexpected rule encountered while parsing constraint: {:?}", r),
 			}
@@ -105,14 +232,17 @@ impl Parser {
 		Ok( Constraint::new(
 			constraint_name.expect("no constraint name should be caught by pest.rs"),
 			constraint_args,
-		) )
+			constraint_named_args,
+		).with_location(location) )
 	} // }}}
 
-	fn parse_provider(&self, provider: Pair<Rule>)
-		-> Result<Provider, ParserError>
+	fn parse_provider(&self, provider: Pair<Rule>, config: &Config)
+		-> Result<Provider, Diagnostic>
 	{ // {{{
+		let location = self.location( provider.as_span() );
 		let mut provider_name: Option<String> = None;
 		let mut provider_args = Vec::with_capacity(2);
+		let mut provider_named_args = Vec::new();
 
 		for provider_item in provider.into_inner() {
 			match provider_item.as_rule() {
@@ -126,7 +256,7 @@ impl Parser {
 					provider_name = Some(name);
 				},
 				Rule::function_call =>
-					self.parse_function_call(provider_item, &mut provider_args)?,
+					self.parse_function_call(provider_item, config, &mut provider_args, &mut provider_named_args)?,
 
 				r => unreachable!("Unexpected rule encountered while parsing provider: {:?}", r),
 			}
@@ -135,20 +265,99 @@ impl Parser {
 		Ok( Provider::new(
 			provider_name.expect("no provider name should be caught by pest.rs"),
 			provider_args,
-		) )
+			provider_named_args,
+		).with_location(location) )
+	} // }}}
+
+	fn parse_type(&self, r#type: Pair<Rule>, config: &Config)
+		-> Result<ColumnType, Diagnostic>
+	{ // {{{
+		let span = r#type.as_span();
+		let mut column_type: Option<ColumnType> = None;
+		let mut type_arguments = Vec::new();
+
+		for type_item in r#type.into_inner() {
+			match type_item.as_rule() {
+				Rule::enum_name => {
+					let enum_type = config.enums.iter()
+						.find( |enum_type| enum_type.name == type_item.as_str() )
+						.ok_or_else( || self.diagnostic(
+							ParserError::UnknownEnum( type_item.as_str().to_string() ),
+							type_item.as_span(),
+						) )?;
+
+					column_type = Some( ColumnType::Enum( enum_type.clone() ) );
+				},
+				Rule::type_name => {
+					column_type = Some(
+						ColumnType::try_from( type_item.as_str().to_string() )
+							.map_err( |e| self.diagnostic( e, type_item.as_span() ) )?
+					);
+				},
+				Rule::type_arguments => {
+					for argument in type_item.into_inner() {
+						let value = argument.as_str();
+
+						type_arguments.push(
+							value.parse()
+								.map_err( |_| self.diagnostic(
+									ParserError::OutOfRange(
+										value.to_string(),
+										"a type argument".to_string(),
+									),
+									argument.as_span(),
+								) )?
+						);
+					}
+				},
+
+				r => unreachable!("Unexpected rule encountered while parsing type: {:?}", r),
+			}
+		}
+
+		column_type.expect("no type name should be caught by pest.rs")
+			.with_arguments(&type_arguments)
+			.map_err( |e| self.diagnostic(e, span) )
+	} // }}}
+
+	/// Joins the text of consecutive doc comments into a single comment. The
+	/// space after the slashes is left out.
+	fn parse_doc_comments(&self, doc_comments: Vec< Pair<Rule> >) -> Option<String> { // {{{
+		if doc_comments.is_empty() {
+			return None;
+		}
+
+		let lines: Vec<&str> = doc_comments.into_iter()
+			.map( |doc_comment| {
+				let text = doc_comment.into_inner()
+					.next()
+					.expect("doc_comment should contain a text")
+					.as_str()
+					.trim_end();
+
+				text.strip_prefix(' ').unwrap_or(text)
+			} )
+			.collect();
+
+		Some( lines.join("\n") )
 	} // }}}
 
-	fn parse_column_definition(&self, column_definition: Pair<Rule>)
-		-> Result<Column, ParserError>
+	fn parse_column_definition(&self, column_definition: Pair<Rule>, config: &Config)
+		-> Result<Column, Diagnostic>
 	{ // {{{
+		let mut doc_comments = Vec::new();
 		let mut column_name: Option<String> = None;
+		let mut location: Option<Location> = None;
 		let mut column_type: Option<ColumnType> = None;
 		let mut constraints = Vec::with_capacity(2);
 		let mut provider: Option<Provider> = None;
 
 		for column_definition_item in column_definition.into_inner() {
 			match column_definition_item.as_rule() {
+				Rule::doc_comment => doc_comments.push(column_definition_item),
 				Rule::column_name => {
+					location = Some( self.location( column_definition_item.as_span() ) );
+
 					let name = column_definition_item.into_inner()
 						.next()
 						.expect("column_definition should contain a column name")
@@ -158,21 +367,18 @@ impl Parser {
 					column_name = Some(name);
 				},
 				Rule::r#type => {
-					let r#type = column_definition_item.as_span()
-						.as_str()
-						.to_string();
-					let r#type = ColumnType::try_from(r#type)?;
-
-					column_type = Some(r#type);
+					column_type = Some(
+						self.parse_type(column_definition_item, config)?
+					);
 				},
 				Rule::constraint => {
 					constraints.push(
-						self.parse_constraint(column_definition_item)?
+						self.parse_constraint(column_definition_item, config)?
 					);
 				},
 				Rule::provider => {
 					provider = Some(
-						self.parse_provider(column_definition_item)?
+						self.parse_provider(column_definition_item, config)?
 					);
 				},
 
@@ -180,74 +386,903 @@ impl Parser {
 			}
 		}
 
-		Ok( Column::new(
-			column_name.expect("no column name should be caught by pest.rs"),
-			column_type.expect("no column type should be caught by pest.rs"),
-			constraints,
-			provider.expect("no provider should be caught by pest.rs"),
-		) )
+		let column_name = column_name.expect("no column name should be caught by pest.rs");
+		let column_type = column_type.expect("no column type should be caught by pest.rs");
+		let location = location.expect("no column name should be caught by pest.rs");
+
+		let provider = match (provider, &column_type) {
+			( Some(provider), _ ) => provider,
+			( None, ColumnType::Enum(_) ) => Provider::new( ENUM_PROVIDER_NAME.to_string(), vec![], vec![] )
+				.with_location( location.clone() ),
+			( None, _ ) => return Err( Diagnostic::new(
+				ParserError::MissingProvider(column_name),
+				Some(location),
+			) ),
+		};
+
+		Ok( Column::new(column_name, column_type, constraints, provider)
+			.with_comment( self.parse_doc_comments(doc_comments) )
+			.with_location(location)
+		)
 	} // }}}
 
+	/// Parses the columns of a table. Columns containing errors are left out
+	/// and their errors are added to `diagnostics`, so the other columns can
+	/// still be checked.
 	fn parse_table_content(
 		&self,
 		table_content: Pair<Rule>,
+		config: &Config,
 		column_output: &mut Vec<Column>,
-	) -> Result<(), ParserError> { // {{{
+		diagnostics: &mut Vec<Diagnostic>,
+	) { // {{{
 		for column_definition in table_content.into_inner() {
 			match column_definition.as_rule() {
 				Rule::column_definition => {
-					let column = self.parse_column_definition(column_definition)?;
-
-					column_output.push(column);
+					match self.parse_column_definition(column_definition, config) {
+						Ok(column) => column_output.push(column),
+						Err(diagnostic) => diagnostics.push(diagnostic),
+					}
 				},
 
 				r => unreachable!("Unexpected rule encountered while parsing table_content: {:?}", r),
 			}
 		}
-
-		Ok(())
 	} // }}}
 
-	fn parse_table_definition(&self, definition: Pair<Rule>)
-		-> Result<Table, ParserError>
+	fn parse_table_row_count(&self, row_count: Pair<Rule>, config: &Config)
+		-> Result<usize, Diagnostic>
 	{ // {{{
+		let span = row_count.as_span();
+		let row_count = row_count.into_inner()
+			.next()
+			.expect("table_row_count should contain a row count");
+
+		let value = match row_count.as_rule() {
+			Rule::UINT => row_count.as_str().to_string(),
+			Rule::constant_name => match config.constants.get( row_count.as_str() ) {
+				Some( Argument::Int(value) ) => value.to_string(),
+				Some(value) => return Err( self.diagnostic(
+					ParserError::Unexpected(
+						value.to_string(),
+						"an int as row count".to_string(),
+					),
+					span,
+				) ),
+				None => return Err( self.diagnostic(
+					ParserError::UnknownConstant( row_count.as_str().to_string() ),
+					span,
+				) ),
+			},
+
+			r => unreachable!("Unexpected rule encountered while parsing table_row_count: {:?}", r),
+		};
+
+		value.parse()
+			.map_err( |_| self.diagnostic(
+				ParserError::OutOfRange(
+					value.to_string(),
+					"a row count".to_string(),
+				),
+				span,
+			) )
+	} // }}}
+
+	/// Returns the columns of the templates in `table_templates`, followed by
+	/// `columns`. A column with the same name as a column of a template
+	/// replaces that column, keeping its position.
+	fn apply_templates(
+		&self,
+		table_templates: Option<Pair<Rule>>,
+		columns: Vec<Column>,
+		config: &Config,
+		diagnostics: &mut Vec<Diagnostic>,
+	) -> Vec<Column> { // {{{
+		let mut output = Vec::with_capacity( columns.len() );
+
+		for template_name in table_templates.into_iter().flat_map( |t| t.into_inner() ) {
+			let name = template_name.as_str().trim();
+
+			match config.templates.iter().find( |template| template.name == name ) {
+				Some(template) => output.extend( template.columns.iter().cloned() ),
+				None => diagnostics.push( self.diagnostic(
+					ParserError::UnknownTemplate( name.to_string() ),
+					template_name.as_span(),
+				) ),
+			}
+		}
+
+		// columns of the table only replace the columns of its templates, so
+		// columns defined twice in the table are reported as duplicates
+		let template_columns = output.len();
+		let mut replaced = HashSet::new();
+
+		for column in columns {
+			match output[..template_columns].iter().position( |c| c.name == column.name ) {
+				Some(i) if replaced.insert(i) => output[i] = column,
+				_ => output.push(column),
+			}
+		}
+
+		output
+	} // }}}
+
+	/// Parses a table or a template and adds it to `config`. The columns of
+	/// the templates of a table are added to it, so the templates have to be
+	/// parsed first.
+	fn parse_mock_definition(&self, definition: Pair<Rule>, config: &mut Config, diagnostics: &mut Vec<Diagnostic>) { // {{{
+		let mut doc_comments = Vec::new();
+		let mut is_template = false;
 		let mut table_name = String::new();
+		let mut location: Option<Location> = None;
+		let mut table_row_count = None;
+		let mut table_templates = None;
 		let mut table_columns = Vec::with_capacity(5);
+		let previous_errors = diagnostics.len();
 
 		for pair in definition.into_inner() {
 			match pair.as_rule() {
-				Rule::table_definition => {
-					table_name = pair.into_inner()
-						.next()
-						.expect("table_definition should contain a table name")
-						.as_str()
-						.trim()
-						.to_string();
+				Rule::doc_comment => doc_comments.push(pair),
+				Rule::table_definition | Rule::template_definition => {
+					is_template = pair.as_rule() == Rule::template_definition;
+
+					for table_definition_item in pair.into_inner() {
+						match table_definition_item.as_rule() {
+							Rule::table_name => {
+								location = Some( self.location( table_definition_item.as_span() ) );
+								table_name = table_definition_item.as_str()
+									.trim()
+									.to_string();
+							},
+							Rule::table_row_count => {
+								match self.parse_table_row_count(table_definition_item, config) {
+									Ok(row_count) => table_row_count = Some(row_count),
+									Err(diagnostic) => diagnostics.push(diagnostic),
+								}
+							},
+							Rule::table_templates => table_templates = Some(table_definition_item),
+
+							r => unreachable!("Unexpected rule encountered while parsing table_definition: {:?}", r),
+						}
+					}
 				},
 				Rule::table_content =>
-					self.parse_table_content(pair, &mut table_columns)?,
+					self.parse_table_content(pair, config, &mut table_columns, diagnostics),
 
 				r => unreachable!("Unexpected rule encountered while parsing mock_definition: {:?}", r),
 			}
 		}
 
-		Ok( Table::new(table_name, table_columns) )
+		let location = location.expect("no table name should be caught by pest.rs");
+		let columns = self.apply_templates(table_templates, table_columns, config, diagnostics);
+
+		// columns with errors are left out, so only report tables that are
+		// empty without them
+		if !is_template && columns.is_empty() && diagnostics.len() == previous_errors {
+			diagnostics.push( Diagnostic::new(
+				ParserError::EmptyTable( table_name.clone() ),
+				Some( location.clone() ),
+			) );
+		}
+
+		let table = Table::new(table_name, table_row_count, columns)
+			.with_comment( self.parse_doc_comments(doc_comments) )
+			.with_location( location.clone() );
+
+		if !is_template {
+			return config.add_table(table);
+		}
+
+		match config.templates.iter().find( |template| template.name == table.name ) {
+			Some(first) => diagnostics.push( Diagnostic::new(
+				ParserError::DuplicateTemplate(
+					table.name.clone(),
+					first.location.as_ref()
+						.map_or_else( || "another location".to_string(), |location| location.to_string() ),
+				),
+				Some(location),
+			) ),
+			None => config.add_template(table),
+		}
 	} // }}}
 
-	pub fn parse(&self) -> Result<Config, ParserError> { // {{{
-		let mut config = Config::new();
+	/// Parses the declaration of an enum and adds it to `config`, so it can be
+	/// used as the type of the columns after it.
+	fn parse_enum_definition(&self, enum_definition: Pair<Rule>, config: &mut Config) -> Result<(), Diagnostic> { // {{{
+		let span = enum_definition.as_span();
+		let mut enum_items = enum_definition.into_inner();
+		let name = enum_items.next()
+			.expect("enum_definition should contain a name")
+			.as_str()
+			.to_string();
+		let mut variants: Vec<EnumVariant> = Vec::new();
+
+		for enum_variant in enum_items {
+			let variant_span = enum_variant.as_span();
+			let mut variant_items = enum_variant.into_inner();
+			let variant_name = variant_items.next()
+				.expect("enum_variant should contain a name")
+				.as_str()
+				.to_string();
+			let weight = match variant_items.next() {
+				Some(weight) => {
+					let value = weight.into_inner()
+						.next()
+						.expect("enum_variant_weight should contain a weight")
+						.as_str();
+
+					value.parse()
+						.map_err( |_| self.diagnostic(
+							ParserError::OutOfRange(
+								value.to_string(),
+								format!("a weight of at most {}", u32::MAX),
+							),
+							variant_span,
+						) )?
+				},
+				None => 1,
+			};
+
+			if variants.iter().any( |variant| variant.name == variant_name ) {
+				return Err( self.diagnostic(
+					ParserError::DuplicateEnumVariant(name, variant_name),
+					variant_span,
+				) );
+			}
+
+			variants.push( EnumVariant::new(variant_name, weight) );
+		}
+
+		if variants.iter().all( |variant| variant.weight == 0 ) {
+			return Err( self.diagnostic( ParserError::ZeroEnumWeights(name), span ) );
+		}
+
+		if config.enums.iter().any( |enum_type| enum_type.name == name ) {
+			return Err( self.diagnostic( ParserError::DuplicateEnum(name), span ) );
+		}
+
+		config.enums.push( Arc::new( EnumType::new(name, variants) ) );
+
+		Ok(())
+	} // }}}
+
+	/// Parses the declaration of a constant and adds its value to `config`.
+	/// A value given on the command line replaces the declared value.
+	fn parse_constant(&self, constant: Pair<Rule>, config: &mut Config) -> Result<(), Diagnostic> { // {{{
+		let span = constant.as_span();
+		let mut constant_items = constant.into_inner();
+		let name = constant_items.next()
+			.expect("constant should contain a name")
+			.as_str()
+			.to_string();
+		let call_arg = constant_items.next()
+			.expect("constant should contain a value");
+
+		let value = self.parse_call_arg(call_arg, config)?;
+
+		if config.constants.contains_key(&name) {
+			return Err( self.diagnostic( ParserError::DuplicateConstant(name), span ) );
+		}
+
+		let value = self.defines.get(&name)
+			.cloned()
+			.unwrap_or(value);
+
+		config.constants.insert(name, value);
 
-		let result = MockerParser::parse( Rule::output, self.file_content.as_str() )
-			.map_err( |e| ParserError::SyntaxError( e.to_string() ) )?
+		Ok(())
+	} // }}}
+
+	/// Parses the imports of a file. The file is resolved relative to the
+	/// importing file and parsed into `config`, unless it has been parsed
+	/// before.
+	fn parse_import(
+		&self,
+		import: Pair<Rule>,
+		config: &mut Config,
+		diagnostics: &mut Vec<Diagnostic>,
+		imports: &mut Imports,
+	) { // {{{
+		let span = import.as_span();
+		let string = import.into_inner()
 			.next()
-			.ok_or( ParserError::EOF )?;
+			.expect("import should contain a path");
+		let import_path = match Argument::try_from(string) {
+			Ok( Argument::String(import_path) ) => import_path,
+			Ok(_) => unreachable!("import should contain a string"),
+			Err(error) => return diagnostics.push( self.diagnostic(error, span) ),
+		};
+
+		let path = match self.source.path.parent() {
+			Some(directory) => directory.join(&import_path),
+			None => PathBuf::from(&import_path),
+		};
+		let canonical = canonical_path(&path);
+
+		if let Some(start) = imports.stack.iter().position( |(parsing, _)| *parsing == canonical ) {
+			let cycle = imports.stack[start..].iter()
+				.map( |(_, given)| given )
+				.chain([&path])
+				.map( |given| given.display().to_string() )
+				.collect::< Vec<String> >()
+				.join(" -> ");
+
+			return diagnostics.push( self.diagnostic( ParserError::ImportCycle(cycle), span ) );
+		}
+
+		if imports.parsed.contains(&canonical) {
+			return;
+		}
+
+		match Parser::new(&path) {
+			Ok(parser) => Parser { defines: self.defines.clone(), ..parser }
+				.parse_file(config, diagnostics, imports),
+			Err(error) => diagnostics.push( self.diagnostic(error, span) ),
+		}
+	} // }}}
+
+	/// Parses the file and the files it imports into `config`. Adds every
+	/// error found to `diagnostics`. Parsing a file stops at its first syntax
+	/// error, because it can't be parsed any further after one.
+	fn parse_file(&self, config: &mut Config, diagnostics: &mut Vec<Diagnostic>, imports: &mut Imports) { // {{{
+		let canonical = canonical_path(&self.source.path);
+
+		imports.parsed.insert( canonical.clone() );
+		imports.stack.push( ( canonical, self.source.path.clone() ) );
+
+		let result = MockerParser::parse( Rule::output, self.source.content.as_str() )
+			.map_err( |e| {
+				let (start, end) = match e.location {
+					InputLocation::Pos(position) => (position, position),
+					InputLocation::Span(span) => span,
+				};
+
+				Diagnostic::new(
+					ParserError::SyntaxError( syntax_error_message(e.variant) ),
+					Some( Location::new( self.source.clone(), start, end ) ),
+				)
+			} )
+			.and_then( |mut pairs| pairs.next()
+				.ok_or_else( || Diagnostic::new(ParserError::Eof, None) )
+			);
+
+		match result {
+			Ok(output) => {
+				for pair in output.into_inner() {
+					match pair.as_rule() {
+						Rule::import => self.parse_import(pair, config, diagnostics, imports),
+						Rule::constant => {
+							if let Err(diagnostic) = self.parse_constant(pair, config) {
+								diagnostics.push(diagnostic);
+							}
+						},
+						Rule::enum_definition => {
+							if let Err(diagnostic) = self.parse_enum_definition(pair, config) {
+								diagnostics.push(diagnostic);
+							}
+						},
+						Rule::mock_definition => self.parse_mock_definition(pair, config, diagnostics),
+						Rule::EOI => break,
+
+						r => unreachable!("Unexpected rule encountered while parsing output: {:?}", r),
+					}
+				}
+			},
+			Err(diagnostic) => diagnostics.push(diagnostic),
+		}
+
+		imports.stack.pop();
+	} // }}}
+
+	/// Parses the config file and every file it imports. Returns every error
+	/// found in the files, but only the first syntax error of each file.
+	pub fn parse(&self) -> Result<Config, Diagnostics> { // {{{
+		let mut config = Config::new();
+		let mut diagnostics = Vec::new();
+
+		self.parse_file( &mut config, &mut diagnostics, &mut Imports::default() );
+
+		finish_parsing(config, diagnostics, &self.defines)
+	} // }}}
+
+	/// Parses every `.mock` file in `directory` and the files they import into
+	/// a single config. Files in subdirectories are only parsed when they are
+	/// imported. `defines` replace the values of constants, like
+	/// `with_defines()` does.
+	pub fn parse_directory(directory: &Path, defines: HashMap<String, Argument>) -> Result<Config, Diagnostics> { // {{{
+		let file_error = |error: String| Diagnostics( vec![ Diagnostic::new(
+			ParserError::FileError( directory.display().to_string(), error ),
+			None,
+		) ] );
+
+		let mut files: Vec<PathBuf> = fs::read_dir(directory)
+			.map_err( |e| file_error( e.to_string() ) )?
+			.filter_map( |entry| entry.ok() )
+			.map( |entry| entry.path() )
+			.filter( |path| path.is_file() && path.extension().is_some_and( |e| e == "mock" ) )
+			.collect();
+
+		if files.is_empty() {
+			return Err( file_error( "the directory contains no .mock files".to_string() ) );
+		}
+
+		// parse in a fixed order, so errors are always reported in the same
+		// order
+		files.sort();
+
+		let mut config = Config::new();
+		let mut diagnostics = Vec::new();
+		let mut imports = Imports::default();
+		let defines = Arc::new(defines);
+
+		for file in files {
+			// files imported by an earlier file are already parsed
+			if imports.parsed.contains( &canonical_path(&file) ) {
+				continue;
+			}
+
+			match Parser::new(&file) {
+				Ok(parser) => Parser { defines: defines.clone(), ..parser }
+					.parse_file(&mut config, &mut diagnostics, &mut imports),
+				Err(error) => diagnostics.push( Diagnostic::new(error, None) ),
+			}
+		}
+
+		finish_parsing(config, diagnostics, &defines)
+	} // }}}
+}
+
+/// Returns the parsed config, or every error found while parsing it. Also
+/// reports constants given on the command line that aren't declared, as
+/// they are probably misspelled.
+fn finish_parsing(
+	config: Config,
+	mut diagnostics: Vec<Diagnostic>,
+	defines: &HashMap<String, Argument>,
+) -> Result<Config, Diagnostics> { // {{{
+	let mut unknown_defines: Vec<&String> = defines.keys()
+		.filter( |name| !config.constants.contains_key(*name) )
+		.collect();
+
+	unknown_defines.sort();
+
+	for name in unknown_defines {
+		diagnostics.push( Diagnostic::new( ParserError::UnknownDefine( name.clone() ), None ) );
+	}
+
+	if !diagnostics.is_empty() {
+		return Err( Diagnostics(diagnostics) );
+	}
+
+	Ok(config)
+} // }}}
+
+#[cfg(test)]
+mod tests {
+	use tempfile::{ tempdir, TempDir };
+
+	use super::*;
+
+	fn parse(file_content: &str) -> Result<Config, Diagnostics> { // {{{
+		let source = Source::new( PathBuf::from("test.mock"), file_content.to_string() );
+
+		Parser { source: Arc::new(source), defines: Arc::new( HashMap::new() ) }.parse()
+	} // }}}
+
+	/// Creates a directory containing the given files
+	fn write_files(files: &[(&str, &str)]) -> TempDir { // {{{
+		let directory = tempdir().unwrap();
+
+		for (name, content) in files {
+			fs::write( directory.path().join(name), content ).unwrap();
+		}
+
+		directory
+	} // }}}
+
+	/// Returns the message and the line and column of every error in
+	/// `file_content`
+	fn parse_errors(file_content: &str) -> Vec<( String, Option<(usize, usize)> )> { // {{{
+		match parse(file_content) {
+			Ok(_) => vec![],
+			Err(diagnostics) => diagnostics.0.iter()
+				.map( |d| ( d.error.to_string(), d.location.as_ref().map( |l| l.line_column() ) ) )
+				.collect(),
+		}
+	} // }}}
+
+	#[test]
+	fn test_parse_should_skip_line_and_block_comments() -> Result<(), Diagnostics> { // {{{
+		let config = parse("
+			// the accounts
+			table Account /* with a block comment */ {
+				id uint #row(), // after a column
+				//// not a doc comment
+				name /* in between */ string #first_name()
+			}
+			// at the end")?;
+
+		assert_eq!(1, config.tables.len());
+		assert_eq!(2, config.tables[0].columns.len());
+		assert_eq!(None, config.tables[0].comment);
+		assert_eq!(None, config.tables[0].columns[1].comment);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_attach_doc_comments_to_tables_and_columns() -> Result<(), Diagnostics> { // {{{
+		let config = parse("
+			/// The accounts
+			///   of the users
+			table Account {
+				/// The id
+				id uint #row(),
+				name string #first_name()
+			}")?;
+
+		let table = &config.tables[0];
+
+		assert_eq!( Some( "The accounts\n  of the users".to_string() ), table.comment );
+		assert_eq!( Some( "The id".to_string() ), table.columns[0].comment );
+		assert_eq!( None, table.columns[1].comment );
 
-		for mock_definition in result.into_inner() {
-			let table = self.parse_table_definition(mock_definition)?;
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_ignore_doc_comments_that_are_not_attached() -> Result<(), Diagnostics> { // {{{
+		let config = parse("
+			/// The states of an account
+			enum State {
+				/// Can log in
+				Active,
+				Blocked
+				/// after the last variant
+			}
+			/// The amount of accounts
+			let ACCOUNTS = 10;
+			table Account(ACCOUNTS) {
+				state State
+				/// after the last column
+			}
+			/// at the end of the file")?;
+
+		let table = &config.tables[0];
+
+		assert_eq!( 1, table.columns.len() );
+		assert_eq!( None, table.columns[0].comment );
+		assert_eq!( 2, config.enums[0].variants.len() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_skip_doc_comments_between_the_parts_of_a_column() -> Result<(), Diagnostics> { // {{{
+		let config = parse("
+			table Account {
+				id int /// not attached
+					#row(),
+				name /// not attached either
+					string #first_name()
+			}")?;
+
+		let table = &config.tables[0];
 
-			config.add_table(table);
+		assert_eq!( 2, table.columns.len() );
+		assert_eq!( None, table.columns[0].comment );
+		assert_eq!( None, table.columns[1].comment );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_not_expect_doc_comments_in_syntax_errors() { // {{{
+		for file_content in [
+			"table T { id uint #row() x }",
+			"table T { id uint #row(), }",
+			"table A { v int #number(0, 5) $null(50) }",
+		] {
+			let result = parse_errors(file_content);
+
+			assert_eq!( 1, result.len() );
+			assert!( result[0].0.starts_with("syntax error"), "{:?}", result );
+			assert!( !result[0].0.contains("doc_comment"), "{:?}", result );
 		}
+	} // }}}
+
+	#[test]
+	fn test_parse_should_parse_named_arguments_after_positional_arguments() -> Result<(), Diagnostics> { // {{{
+		let config = parse("table Account { id int #number(5, max = 10) }")?;
+
+		let provider = &config.tables[0].columns[0].provider;
+
+		assert_eq!( vec![ Argument::Int(5) ], provider.arguments );
+		assert_eq!(
+			vec![ NamedArgument::new( "max".to_string(), Argument::Int(10) ) ],
+			provider.named_arguments,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_for_positional_arguments_after_named_arguments() { // {{{
+		let expected = ParserError::Unexpected(
+			"5".to_string(),
+			"a named argument, positional arguments have to come first".to_string(),
+		);
+
+		let result = parse_errors("table Account { id int #number(max = 10, 5) }");
+
+		assert_eq!( vec![ ( expected.to_string(), Some( (1, 42) ) ) ], result );
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_for_text_after_the_last_table() { // {{{
+		let result = parse_errors("table Account { id uint #row() } unknown");
+
+		assert_eq!(1, result.len());
+		assert!( result[0].0.starts_with("syntax error") );
+		assert_eq!( Some( (1, 34) ), result[0].1 );
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_every_error_with_its_location() { // {{{
+		let result = parse_errors("table Account {
+	id string(0) #row(),
+	amount int #number(99999999999999999999)
+}");
+
+		assert_eq!(
+			vec![
+				(
+					ParserError::Unexpected( "0".to_string(), "a length of at least 1".to_string() ).to_string(),
+					Some( (2, 5) ),
+				),
+				(
+					ParserError::OutOfRange(
+						"99999999999999999999".to_string(),
+						format!("an int between {} and {}", i64::MIN, i64::MAX),
+					).to_string(),
+					Some( (3, 21) ),
+				),
+			],
+			result,
+		);
+	} // }}}
+
+	#[test]
+	fn test_parse_should_parse_imported_files_once() -> Result<(), Diagnostics> { // {{{
+		let directory = write_files(&[
+			( "main.mock", "import \"accounts.mock\"\nimport \"orders.mock\"" ),
+			( "accounts.mock", "table Account { id uint #row() }" ),
+			( "orders.mock", "import \"accounts.mock\"\ntable Orders { id uint #row() }" ),
+		]);
+
+		let config = Parser::new( &directory.path().join("main.mock") )
+			.unwrap()
+			.parse()?;
+
+		let names: Vec<&str> = config.tables.iter()
+			.map( |table| table.name.as_str() )
+			.collect();
+
+		assert_eq!( vec!["Account", "Orders"], names );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_for_import_cycles() { // {{{
+		let directory = write_files(&[
+			( "a.mock", "import \"b.mock\"\ntable A { id uint #row() }" ),
+			( "b.mock", "import \"a.mock\"\ntable B { id uint #row() }" ),
+		]);
+		let a = directory.path().join("a.mock");
+		let b = directory.path().join("b.mock");
+
+		let result = Parser::new(&a)
+			.unwrap()
+			.parse();
+
+		let Err(diagnostics) = result else {
+			panic!("an import cycle should be an error");
+		};
+		let expected = ParserError::ImportCycle( format!(
+			"{} -> {} -> {}",
+			a.display(),
+			b.display(),
+			a.display(),
+		) );
+
+		assert_eq!(1, diagnostics.0.len());
+		assert_eq!( expected.to_string(), diagnostics.0[0].error.to_string() );
+		assert_eq!( Some(&b), diagnostics.0[0].location.as_ref().map( |l| l.path() ) );
+	} // }}}
+
+	#[test]
+	fn test_parse_directory_should_parse_every_mock_file() -> Result<(), Diagnostics> { // {{{
+		let directory = write_files(&[
+			( "orders.mock", "import \"accounts.mock\"\ntable Orders { id uint #row() }" ),
+			( "accounts.mock", "table Account { id uint #row() }" ),
+			( "notes.txt", "not a mock file" ),
+		]);
+
+		let config = Parser::parse_directory( directory.path(), HashMap::new() )?;
+
+		let names: Vec<&str> = config.tables.iter()
+			.map( |table| table.name.as_str() )
+			.collect();
+
+		assert_eq!( vec!["Account", "Orders"], names );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_add_the_columns_of_templates_to_tables() -> Result<(), Diagnostics> { // {{{
+		let config = parse("
+			template Audited {
+				id uint #row(),
+				created_at date #date(),
+				deleted_at date #date()
+			}
+
+			table Orders : Audited {
+				created_at date_time #date_time(),
+				amount int #number()
+			}")?;
+
+		let table = &config.tables[0];
+		let columns: Vec<(&str, ColumnType)> = table.columns.iter()
+			.map( |column| (column.name.as_str(), column.kind.clone()) )
+			.collect();
+
+		assert_eq!(1, config.tables.len());
+		assert_eq!(
+			vec![
+				("id", ColumnType::UnsignedInt),
+				("created_at", ColumnType::DateTime),
+				("deleted_at", ColumnType::Date),
+				("amount", ColumnType::Int),
+			],
+			columns,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_keep_columns_defined_twice_in_a_table() -> Result<(), Diagnostics> { // {{{
+		let config = parse("
+			template Audited {
+				id uint #row()
+			}
+
+			table Orders : Audited {
+				id int #number(0, 5),
+				id string #gender()
+			}")?;
+
+		let kinds: Vec<ColumnType> = config.tables[0].columns.iter()
+			.map( |column| column.kind.clone() )
+			.collect();
+
+		// the first replaces the column of the template, the second is left
+		// for the check to report
+		assert_eq!( vec![ ColumnType::Int, ColumnType::String(usize::MAX) ], kinds );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_for_unknown_templates() { // {{{
+		let expected = ParserError::UnknownTemplate( "Audited".to_string() );
+
+		let result = parse_errors("table Orders : Audited {}");
+
+		assert_eq!( vec![ ( expected.to_string(), Some( (1, 16) ) ) ], result );
+	} // }}}
+
+	#[test]
+	fn test_parse_should_replace_constants_by_their_value() -> Result<(), Diagnostics> { // {{{
+		let config = parse("
+			let ROWS = 5;
+			let MAX = 100;
+			let LIMIT = MAX;
+
+			table Account(ROWS) { id int #number(1, max = LIMIT) }")?;
+
+		let table = &config.tables[0];
+
+		assert_eq!( Some(5), table.row_count );
+		assert_eq!( vec![ Argument::Int(1) ], table.columns[0].provider.arguments );
+		assert_eq!(
+			vec![ NamedArgument::new( "max".to_string(), Argument::Int(100) ) ],
+			table.columns[0].provider.named_arguments,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_replace_constants_by_defines() -> Result<(), Diagnostics> { // {{{
+		let source = Source::new( PathBuf::from("test.mock"), "let MAX = 100;\ntable A { id int #number(MAX) }".to_string() );
+		let defines = HashMap::from([ ( "MAX".to_string(), Argument::Int(5) ) ]);
+
+		let config = Parser { source: Arc::new(source), defines: Arc::new(defines) }.parse()?;
+
+		assert_eq!( vec![ Argument::Int(5) ], config.tables[0].columns[0].provider.arguments );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_for_unknown_constants() { // {{{
+		let expected = ParserError::UnknownConstant( "MAX".to_string() );
+
+		let result = parse_errors("table A { id int #number(MAX) }\nlet MAX = 100;");
+
+		assert_eq!( vec![ ( expected.to_string(), Some( (1, 26) ) ) ], result );
+	} // }}}
+
+	#[test]
+	fn test_parse_should_give_enum_columns_the_enum_provider() -> Result<(), Diagnostics> { // {{{
+		let config = parse("
+			enum Status { Active(3), Suspended, Closed(0) }
+
+			table Account { status Status }")?;
+
+		let column = &config.tables[0].columns[0];
+
+		assert_eq!( ENUM_PROVIDER_NAME, column.provider.name );
+		assert_eq!( ColumnType::Enum( config.enums[0].clone() ), column.kind );
+		assert_eq!(
+			vec![
+				EnumVariant::new( "Active".to_string(), 3 ),
+				EnumVariant::new( "Suspended".to_string(), 1 ),
+				EnumVariant::new( "Closed".to_string(), 0 ),
+			],
+			config.enums[0].variants,
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_for_invalid_enums() { // {{{
+		let expected = vec![
+			( ParserError::DuplicateEnumVariant( "A".to_string(), "X".to_string() ).to_string(), Some( (1, 13) ) ),
+			( ParserError::ZeroEnumWeights( "B".to_string() ).to_string(), Some( (2, 1) ) ),
+			( ParserError::UnknownEnum( "C".to_string() ).to_string(), Some( (3, 13) ) ),
+			( ParserError::MissingProvider( "id".to_string() ).to_string(), Some( (4, 11) ) ),
+		];
+
+		let result = parse_errors("enum A { X, X }\nenum B { Y(0) }\ntable T { c C }\ntable U { id int }");
+
+		assert_eq!(expected, result);
+	} // }}}
+
+	#[test]
+	fn test_parse_should_parse_the_row_count_of_tables() -> Result<(), Diagnostics> { // {{{
+		let config = parse("table Orders(100000) { id uint #row() }\ntable Tags { id uint #row() }")?;
+
+		assert_eq!( Some(100000), config.tables[0].row_count );
+		assert_eq!( None, config.tables[1].row_count );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_for_row_counts_out_of_range() { // {{{
+		let expected = ParserError::OutOfRange(
+			"99999999999999999999999".to_string(),
+			"a row count".to_string(),
+		);
+
+		let result = parse_errors("table Orders(99999999999999999999999) { id uint #row() }");
 
-		Ok(config)
+		assert_eq!( vec![ ( expected.to_string(), Some( (1, 13) ) ) ], result );
 	} // }}}
 }
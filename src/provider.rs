@@ -1,13 +1,27 @@
+use rand::{
+	rngs::mock::StepRng,
+	RngCore,
+};
+use std::{
+	cell::RefCell,
+	rc::Rc,
+};
 use thiserror::Error;
 
 use crate::{
-	generator::CellValue,
+	generator::{ CellValue, MockData },
+	parameter::Parameter,
 	parser::config::{ Argument, ColumnType },
+	rng::column_rng,
 };
 
 #[derive(Debug, Error)]
 #[cfg_attr( test, derive(PartialEq) )]
 pub enum ProviderError {
+	/// Used when provider {0} isn't registered under its name
+	#[error("Unknown provider '{0}'")]
+	UnknownProvider(String),
+
 	/// Used when an argument given to a [`ProviderImpl`] is not correct.
 	/// `Unexpected {0}, expected {1}`
 	#[error("Unexpected argument '{0}', expected {1}")]
@@ -22,34 +36,147 @@ pub enum ProviderError {
 	#[error("Incompatible types. Provider provided '{1}', but '{0}' was needed")]
 	IncompatibleType(ColumnType, CellValue),
 
+	/// Used when provider {0} provides values of type {1}, which don't fit in
+	/// its column of type {2}. Found before any data is generated
+	#[error("'{0}' provides values of type '{1}', but the column is of type '{2}'")]
+	IncompatibleProvider(String, ColumnType, ColumnType),
+
+	/// Used when a provider links to column {0}, but that column doesn't
+	/// contain any values to link to
+	#[error("Column '{0}' can't be linked to, because it has no values or has not been generated yet")]
+	LinkTargetUnavailable(String),
+
+	/// Used when provider {0} didn't provide a value accepted by the
+	/// constraints of column {1} within {2} attempts
+	#[error("Provider '{0}' could not provide a unique value for column '{1}' within {2} attempts. It probably can't provide enough distinct values for the amount of rows")]
+	UniqueValuesExhausted(String, String, usize),
+
+	/// Used when provider {0} can only provide {2} distinct values for column
+	/// {1}, which requires {3} unique values. Rows that are expected to be null
+	/// aren't counted.
+	#[error("Provider '{0}' can only provide {2} distinct values for column '{1}', but {3} unique values are needed")]
+	TooFewDistinctValues(String, String, usize, usize),
+
+	/// Used when provider {0} can't provide values for a column of type {1}
+	#[error("Provider '{0}' can't provide values for columns of type '{1}'")]
+	UnsupportedColumnType(String, ColumnType),
+
+	/// Used when the weights given to a provider can't be used to choose
+	/// values, for example because they're negative. {0} describes why
+	#[error("Invalid weights: {0}")]
+	InvalidWeights(String),
+
+	/// Used when the distribution given to a numeric provider is unknown or
+	/// its parameters are invalid. {0} describes why
+	#[error("Invalid distribution: {0}")]
+	InvalidDistribution(String),
+
 	/// Unknown error {0}
 	#[error("An unknown error occurred: {0}")]
 	Unknown(String),
 }
 
+/// The data a provider is created from. Every column gets its own provider,
+/// so this describes a single column.
 pub struct ProviderCreationData {
-	pub row_count: usize,
+	/// The name of the table the column belongs to
+	pub table: String,
+	/// The name of the column the provider provides values for
+	pub column: String,
+	/// The type of the column. Providers providing strings should keep them
+	/// within its maximum length, for example using `truncate()`.
+	pub kind: ColumnType,
+	/// The arguments given to the provider in the config
+	pub arguments: Vec<Argument>,
+	/// All data that has been generated so far. Can be used by providers that
+	/// base their values on other columns.
+	pub generated_data: Rc< RefCell<MockData> >,
+	/// The seed used to create the random number generators of the providers.
+	/// `None` when the output should be random every time.
+	pub seed: Option<u64>,
 }
 
+impl ProviderCreationData { // {{{
+	/// Creates the random number generator the provider should use. Providers
+	/// that use random values should use it in `provide()`, so the output can
+	/// be reproduced using a seed. Returns predictable values in tests.
+	pub fn rng(&self) -> Box<dyn RngCore> {
+		if cfg!(test) {
+			return Box::new( StepRng::new(0, 1) );
+		}
+
+		column_rng( self.seed, &[&self.table, &self.column] )
+	}
+
+	/// Creates the data of a provider that only gets `arguments`, for testing
+	/// providers without a config
+	#[cfg(test)]
+	pub fn with_arguments(arguments: Vec<Argument>) -> Self {
+		Self {
+			arguments,
+			..Self::default()
+		}
+	}
+
+	/// Shortens `value` to the maximum length of the column, if it has one.
+	pub fn truncate(&self, value: &str) -> String {
+		match self.kind.max_length() {
+			Some(max_length) => value.chars()
+				.take(max_length)
+				.collect(),
+			None => value.to_string(),
+		}
+	}
+} // }}}
+
+impl Default for ProviderCreationData { // {{{
+	fn default() -> Self {
+		Self {
+			table: String::new(),
+			column: String::new(),
+			kind: ColumnType::String(usize::MAX),
+			arguments: vec![],
+			generated_data: Rc::new( RefCell::new( MockData::new() ) ),
+			seed: None,
+		}
+	}
+} // }}}
+
 pub trait ProviderImpl { // {{{
-	/// Used to create a new provider. Can also be used to, for example,
+	/// Used to create a new provider for a single column. Should parse the
+	/// arguments of the provider and can also be used to, for example,
 	/// initialize a list of items that `provide()` can return.
 	///
 	/// # Arguments
 	///
-	/// - `row_count` The amount of rows to generate for every column.
+	/// - `data` The column the provider is created for
 	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError>
 		where Self: Sized;
 
-	/// Gets called before a new table is filled. This method can be used to,
-	/// for example, reset a counter used by `provide()`.
-	///
-	/// # Arguments
-	///
-	/// - `arguments` A list with arguments for the provider to use for the
-	///   current column
-	fn reset(&mut self, _arguments: &Vec<Argument>) -> Result<(), ProviderError> {
-		Ok(())
+	/// Returns the parameters of the provider. The arguments given in the
+	/// config are checked against them and passed to `new()` in the order of
+	/// the parameters. Providers without parameters don't accept any
+	/// arguments.
+	fn parameters() -> Vec<Parameter>
+		where Self: Sized
+	{
+		Vec::new()
+	}
+
+	/// Returns the amount of distinct values the provider can provide with the
+	/// arguments of its column, or `None` when there is no limit or it
+	/// isn't known. Used to detect columns requiring unique values before they
+	/// are generated.
+	fn distinct_values(&self) -> Option<usize> {
+		None
+	}
+
+	/// Returns the type of the values the provider provides with the arguments
+	/// of its column, or `None` when it isn't known before generating. Used
+	/// to detect providers that don't fit their column before any data is
+	/// generated.
+	fn provided_type(&self) -> Option<ColumnType> {
+		None
 	}
 
 	/// Gets called every time a row is created. Should return the item for the
@@ -0,0 +1,59 @@
+use chrono::NaiveDate;
+
+use crate::{
+	generator::{ CellValue, DATE_FORMAT },
+	parser::config::ColumnType,
+	providers::temporal::{ Temporal, TemporalProvider },
+};
+
+/// Provides a random date between a minimum and maximum, which are given as
+/// strings in the format `YYYY-MM-DD`.
+pub type DateProvider = TemporalProvider<NaiveDate>;
+
+impl Temporal for NaiveDate {
+	const FORMATS: &'static [&'static str] = &[DATE_FORMAT];
+	const FORMAT_DESCRIPTION: &'static str = "YYYY-MM-DD";
+	const DESCRIPTION: &'static str = "a date";
+	const DEFAULT_MIN: &'static str = "1970-01-01";
+	const DEFAULT_MAX: &'static str = "2099-12-31";
+	const STEP_SECONDS: i64 = 24 * 60 * 60;
+
+	fn parse(value: &str, format: &str) -> Option<Self> {
+		Self::parse_from_str(value, format).ok()
+	}
+
+	fn format(&self, format: &str) -> String {
+		NaiveDate::format(self, format).to_string()
+	}
+
+	fn column_type() -> ColumnType {
+		ColumnType::Date
+	}
+
+	fn cell_value(self) -> CellValue {
+		CellValue::Date(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		provider::{ ProviderCreationData, ProviderImpl, ProviderError },
+		parser::config::Argument,
+	};
+
+	use super::*;
+
+	#[test]
+	fn test_distinct_values_should_include_minimum_and_maximum() -> Result<(), ProviderError> { // {{{
+		let sut = DateProvider::new( &ProviderCreationData::with_arguments( vec![
+			Argument::String( "2020-02-28".to_string() ),
+			Argument::String( "2020-03-01".to_string() ),
+		] ) )?;
+
+		// 2020 is a leap year
+		assert_eq!( Some(3), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+}
@@ -0,0 +1,79 @@
+use chrono::NaiveDateTime;
+
+use crate::{
+	generator::{ CellValue, DATE_TIME_FORMAT },
+	parser::config::ColumnType,
+	providers::temporal::{ Temporal, TemporalProvider },
+};
+
+/// Provides a random date with a time between a minimum and maximum, which are
+/// given as strings in the format `YYYY-MM-DD HH:MM:SS`.
+pub type DateTimeProvider = TemporalProvider<NaiveDateTime>;
+
+impl Temporal for NaiveDateTime {
+	/// Besides ISO 8601, the date and time can also be separated by a space
+	const FORMATS: &'static [&'static str] = &[DATE_TIME_FORMAT, "%Y-%m-%d %H:%M:%S"];
+	const FORMAT_DESCRIPTION: &'static str = "YYYY-MM-DD HH:MM:SS";
+	const DESCRIPTION: &'static str = "a date and time";
+	const DEFAULT_MIN: &'static str = "1970-01-01 00:00:00";
+	const DEFAULT_MAX: &'static str = "2099-12-31 23:59:59";
+	const STEP_SECONDS: i64 = 1;
+
+	fn parse(value: &str, format: &str) -> Option<Self> {
+		Self::parse_from_str(value, format).ok()
+	}
+
+	fn format(&self, format: &str) -> String {
+		NaiveDateTime::format(self, format).to_string()
+	}
+
+	fn column_type() -> ColumnType {
+		ColumnType::DateTime
+	}
+
+	fn cell_value(self) -> CellValue {
+		CellValue::DateTime(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		provider::{ ProviderCreationData, ProviderImpl, ProviderError },
+		parser::config::Argument,
+	};
+
+	use super::*;
+
+	fn creation_data(arguments: &[&str]) -> ProviderCreationData { // {{{
+		ProviderCreationData::with_arguments(
+			arguments.iter()
+				.map( |arg| Argument::String( arg.to_string() ) )
+				.collect()
+		)
+	} // }}}
+
+	#[test]
+	fn test_new_should_accept_iso_8601() -> Result<(), ProviderError> { // {{{
+		let sut = DateTimeProvider::new( &creation_data( &["2020-01-01T00:00:00", "2020-01-01T00:01:00"] ) )?;
+
+		assert_eq!( Some(61), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_no_time_is_given() -> Result<(), ProviderError> { // {{{
+		let expected = Err( ProviderError::UnexpectedArgument(
+			"2020-01-01".to_string(),
+			"a date and time in the format 'YYYY-MM-DD HH:MM:SS'".to_string(),
+		) );
+
+		let result = DateTimeProvider::new( &creation_data( &["2020-01-01"] ) )
+			.map( |sut| sut.distinct_values() );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+}
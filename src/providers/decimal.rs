@@ -0,0 +1,235 @@
+use rand::{
+	prelude::Rng,
+	RngCore,
+};
+
+use crate::{
+	decimal::{
+		Decimal,
+		Rounding,
+		MAX_PRECISION,
+	},
+	distribution::Distribution,
+	generator::CellValue,
+	parameter::{ Parameter, ParameterKind },
+	provider::{
+		ProviderCreationData,
+		ProviderImpl,
+		ProviderError,
+	},
+	parser::config::{ Argument, ColumnType },
+};
+
+/// The scale used when the column isn't a decimal column, for example a float
+/// column
+const DEFAULT_SCALE: u32 = 2;
+
+/// Provides a random decimal between a minimum and maximum. The values have
+/// the scale of the column, so they can be written without rounding.
+pub struct DecimalProvider {
+	rng: Box<dyn RngCore>,
+	/// The minimum, multiplied by 10 to the power of the scale
+	min: i128,
+	/// The maximum, multiplied by 10 to the power of the scale
+	max: i128,
+	scale: u32,
+	distribution: Distribution,
+}
+
+impl DecimalProvider {
+	/// Returns the factor between a value and its representation with `scale`
+	/// decimals
+	fn factor(scale: u32) -> f64 {
+		10f64.powi(scale as i32)
+	}
+
+	fn parse_value_from_arg(arg: &Argument, scale: u32, rounding: Rounding) -> Result<Decimal, ProviderError> {
+		let value = match arg {
+			Argument::Int(_) | Argument::Float(_) => Decimal::parse( &arg.to_string(), scale, rounding ),
+
+			_ => None,
+		};
+
+		value.ok_or_else( || ProviderError::UnexpectedArgument(
+			arg.to_string(),
+			"int or float".to_string(),
+		) )
+	}
+}
+
+impl ProviderImpl for DecimalProvider {
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		let (precision, scale) = match data.kind {
+			ColumnType::Decimal(precision, scale) => (precision, scale),
+
+			_ => (MAX_PRECISION, DEFAULT_SCALE),
+		};
+		let largest = Decimal::max(precision, scale);
+
+		// round the bounds inwards, so every value is within the range
+		let min = match data.arguments.first() {
+			Some(min) => Self::parse_value_from_arg(min, scale, Rounding::Up)?,
+			None => Decimal::new(0, scale),
+		};
+		let max = match data.arguments.get(1) {
+			Some(max) => Self::parse_value_from_arg(max, scale, Rounding::Down)?,
+			None => largest,
+		};
+
+		for bound in [min, max] {
+			if bound.value().unsigned_abs() > largest.value().unsigned_abs() {
+				return Err( ProviderError::UnexpectedArgument(
+					bound.to_string(),
+					format!("a number that fits in {}", data.kind),
+				) );
+			}
+		}
+
+		let distribution = Distribution::new(
+			data.arguments.get(2),
+			data.arguments.get(3..).unwrap_or_default(),
+			min.value() as f64 / Self::factor(scale),
+			max.value() as f64 / Self::factor(scale),
+		)?;
+
+		Ok( Self {
+			rng: data.rng(),
+			min: min.value(),
+			max: max.value(),
+			scale,
+			distribution,
+		} )
+	}
+
+	fn parameters() -> Vec<Parameter> {
+		[
+			vec![
+				Parameter::new("min", ParameterKind::Number).with_default( Argument::Int(0) ),
+				// defaults to the largest value of the column
+				Parameter::new("max", ParameterKind::Number),
+			],
+			Distribution::parameters(),
+		].concat()
+	}
+
+	fn distinct_values(&self) -> Option<usize> {
+		let count = self.max.saturating_sub(self.min).saturating_add(1);
+
+		Some( usize::try_from(count).unwrap_or(usize::MAX) )
+	}
+
+	fn provided_type(&self) -> Option<ColumnType> {
+		let largest = self.min.unsigned_abs().max( self.max.unsigned_abs() );
+		let integer_digits = Decimal::new(largest as i128, self.scale).integer_digits();
+
+		Some( ColumnType::Decimal(integer_digits + self.scale, self.scale) )
+	}
+
+	fn provide(&mut self) -> Result<CellValue, ProviderError> {
+		let value = match self.distribution.sample( &mut self.rng ) {
+			// casting saturates, so values outside of i128 end up at the bounds
+			Some(value) => ( (value * Self::factor(self.scale)).round() as i128 ).clamp(self.min, self.max),
+			None => self.rng.gen_range(self.min..=self.max),
+		};
+
+		Ok( CellValue::Decimal( Decimal::new(value, self.scale) ) )
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn creation_data(kind: ColumnType, arguments: Vec<Argument>) -> ProviderCreationData { // {{{
+		ProviderCreationData {
+			kind,
+			..ProviderCreationData::with_arguments(arguments)
+		}
+	} // }}}
+
+	#[test]
+	fn test_provide_should_return_the_minimum_with_the_scale_of_the_column() -> Result<(), ProviderError> { // {{{
+		let mut sut = DecimalProvider::new( &creation_data(
+			ColumnType::Decimal(5, 2),
+			vec![ Argument::Float(1.5), Argument::Int(10) ],
+		) )?;
+
+		let result = sut.provide()?;
+
+		assert_eq!( CellValue::Decimal( Decimal::new(150, 2) ), result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_round_the_bounds_inwards() -> Result<(), ProviderError> { // {{{
+		let sut = DecimalProvider::new( &creation_data(
+			ColumnType::Decimal(5, 1),
+			vec![ Argument::Float(0.25), Argument::Float(0.75) ],
+		) )?;
+
+		assert_eq!(3, sut.min);
+		assert_eq!(7, sut.max);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_default_to_the_largest_value_of_the_column() -> Result<(), ProviderError> { // {{{
+		let sut = DecimalProvider::new( &creation_data( ColumnType::Decimal(4, 2), vec![] ) )?;
+
+		assert_eq!(0, sut.min);
+		assert_eq!(9999, sut.max);
+		assert_eq!( Some(10000), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_the_maximum_does_not_fit() -> Result<(), ProviderError> { // {{{
+		let expected = Err( ProviderError::UnexpectedArgument(
+			"100.00".to_string(),
+			"a number that fits in decimal(4, 2)".to_string(),
+		) );
+
+		let result = DecimalProvider::new( &creation_data(
+			ColumnType::Decimal(4, 2),
+			vec![ Argument::Int(0), Argument::Int(100) ],
+		) )
+			.map( |sut| sut.max );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_no_number_is_given() -> Result<(), ProviderError> { // {{{
+		let expected = Err( ProviderError::UnexpectedArgument(
+			"abc".to_string(),
+			"int or float".to_string(),
+		) );
+
+		let result = DecimalProvider::new( &creation_data(
+			ColumnType::Float,
+			vec![ Argument::String( "abc".to_string() ) ],
+		) )
+			.map( |sut| sut.min );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_provided_type_should_only_count_the_digits_of_the_bounds() -> Result<(), ProviderError> { // {{{
+		let sut = DecimalProvider::new( &creation_data(
+			ColumnType::Float,
+			vec![ Argument::Int(-150), Argument::Int(10) ],
+		) )?;
+
+		assert_eq!( Some( ColumnType::Decimal(5, 2) ), sut.provided_type() );
+
+		Ok(())
+	} // }}}
+}
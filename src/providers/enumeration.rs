@@ -0,0 +1,123 @@
+use rand::RngCore;
+use std::sync::Arc;
+
+use crate::{
+	alias::AliasTable,
+	generator::CellValue,
+	provider::{
+		ProviderCreationData,
+		ProviderImpl,
+		ProviderError,
+	},
+	parser::config::{ ColumnType, EnumType },
+};
+
+/// The name of the provider used for columns of an enum type that don't have
+/// a provider of their own
+pub const ENUM_PROVIDER_NAME: &str = "enum";
+
+/// Provides the variants of the enum its column is of, chosen according to
+/// their weights.
+pub struct EnumProvider {
+	rng: Box<dyn RngCore>,
+	enum_type: Arc<EnumType>,
+	table: AliasTable,
+}
+
+impl ProviderImpl for EnumProvider {
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		let ColumnType::Enum(enum_type) = &data.kind else {
+			return Err( ProviderError::UnsupportedColumnType(
+				ENUM_PROVIDER_NAME.to_string(),
+				data.kind.clone(),
+			) );
+		};
+
+		let weights: Vec<f64> = enum_type.variants.iter()
+			.map( |variant| f64::from(variant.weight) )
+			.collect();
+		let table = AliasTable::new(&weights)
+			.ok_or_else( || ProviderError::InvalidWeights( format!(
+				"every variant of enum '{}' has a weight of 0",
+				enum_type.name,
+			) ) )?;
+
+		Ok( Self {
+			rng: data.rng(),
+			enum_type: enum_type.clone(),
+			table,
+		} )
+	}
+
+	fn distinct_values(&self) -> Option<usize> {
+		Some(
+			self.enum_type.variants.iter()
+				.filter( |variant| variant.weight > 0 )
+				.count()
+		)
+	}
+
+	fn provided_type(&self) -> Option<ColumnType> {
+		Some( ColumnType::Enum( self.enum_type.clone() ) )
+	}
+
+	fn provide(&mut self) -> Result<CellValue, ProviderError> {
+		let selected = self.table.sample( &mut self.rng );
+
+		Ok( CellValue::String( self.enum_type.variants[selected].name.clone() ) )
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::parser::config::EnumVariant;
+
+	fn creation_data(variants: Vec<(&str, u32)>) -> ProviderCreationData { // {{{
+		let variants = variants.into_iter()
+			.map( |(name, weight)| EnumVariant::new( name.to_string(), weight ) )
+			.collect();
+
+		ProviderCreationData {
+			kind: ColumnType::Enum( Arc::new( EnumType::new( "Status".to_string(), variants ) ) ),
+			..ProviderCreationData::default()
+		}
+	} // }}}
+
+	#[test]
+	fn test_provide_should_skip_variants_with_a_weight_of_0() -> Result<(), ProviderError> { // {{{
+		let mut sut = EnumProvider::new( &creation_data( vec![ ("Active", 0), ("Closed", 3) ] ) )?;
+
+		assert_eq!( CellValue::String( "Closed".to_string() ), sut.provide()? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_distinct_values_should_not_count_variants_with_a_weight_of_0() -> Result<(), ProviderError> { // {{{
+		let sut = EnumProvider::new( &creation_data( vec![
+			("Active", 2),
+			("Suspended", 0),
+			("Closed", 1),
+		] ) )?;
+
+		assert_eq!( Some(2), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_the_column_is_no_enum() { // {{{
+		let expected = ProviderError::UnsupportedColumnType(
+			ENUM_PROVIDER_NAME.to_string(),
+			ColumnType::Int,
+		);
+
+		let result = EnumProvider::new( &ProviderCreationData {
+			kind: ColumnType::Int,
+			..ProviderCreationData::default()
+		} );
+
+		assert_eq!( Some(expected), result.err() );
+	} // }}}
+}
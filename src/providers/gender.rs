@@ -1,17 +1,16 @@
 use rand::{
 	prelude::SliceRandom, RngCore
 };
-#[cfg(test)]
-use rand::rngs::mock::StepRng;
 
 use crate::{
 	generator::CellValue,
+	parameter::{ Parameter, ParameterKind },
 	provider::{
 		ProviderCreationData,
 		ProviderImpl,
 		ProviderError,
 	},
-	parser::config::Argument,
+	parser::config::{ Argument, ColumnType },
 };
 
 struct Gender {
@@ -36,70 +35,65 @@ const GENDER_LIST: [Gender; 3] = [
 
 pub struct GenderProvider {
 	rng: Box<dyn RngCore>,
-	long: bool,
+	/// The genders in the form they are provided in
+	genders: Vec<String>,
 }
 
 impl ProviderImpl for GenderProvider {
-	#[cfg( not(test) )]
-	fn new(_data: &ProviderCreationData) -> Result<Self, ProviderError> {
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		let long = match data.arguments.first() {
+			Some( Argument::Boolean(long) ) => *long,
+			Some(arg) => return Err( ProviderError::UnexpectedArgument(
+				arg.to_string(),
+				"Boolean".to_string(),
+			) ),
+			None => false,
+		};
+
+		let genders = GENDER_LIST.iter()
+			.map( |gender| match long {
+				true => data.truncate(gender.long),
+				false => data.truncate( &gender.short.to_string() ),
+			} )
+			.collect();
+
 		Ok( Self {
-			rng: Box::new( rand::thread_rng() ),
-			long: false,
+			rng: data.rng(),
+			genders,
 		} )
 	}
 
-	#[cfg(test)]
-	fn new(_data: &ProviderCreationData) -> Result<Self, ProviderError> {
-		Ok( Self {
-			rng: Box::new( StepRng::new(0, 1) ),
-			long: false,
-		} )
+	fn parameters() -> Vec<Parameter> {
+		vec![
+			Parameter::new("long", ParameterKind::Boolean).with_default( Argument::Boolean(false) ),
+		]
 	}
 
-	fn reset(&mut self, arguments: &Vec<Argument>) -> Result<(), ProviderError> {
-		if let Some(should_be_long_arg) = arguments.get(0) {
-			match should_be_long_arg {
-				Argument::Boolean(should_be_long) => {
-					self.long = *should_be_long;
-				},
-				arg => return Err( ProviderError::UnexpectedArgument(
-					arg.to_string(),
-					"Boolean".to_string(),
-				) ),
-			}
-		} else {
-			self.long = false;
-		}
+	fn distinct_values(&self) -> Option<usize> {
+		Some( GENDER_LIST.len() )
+	}
 
-		Ok(())
+	fn provided_type(&self) -> Option<ColumnType> {
+		Some( ColumnType::String(usize::MAX) )
 	}
 
 	fn provide(&mut self) -> Result<CellValue, ProviderError> {
-		let gender = GENDER_LIST.choose(&mut self.rng)
+		let gender = self.genders.choose(&mut self.rng)
 			.expect("GENDER_LIST should not be empty");
 
-		let value = if self.long {
-			gender.long.to_string()
-		} else {
-			gender.short.to_string()
-		};
-
-		Ok( CellValue::String( value.to_string() ) )
+		Ok( CellValue::String( gender.clone() ) )
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-
-	const CREATION_DATA: ProviderCreationData = ProviderCreationData { row_count: 1000 };
+	use crate::parser::config::ColumnType;
 
 	#[test]
 	fn test_provide_returns_gender() -> Result<(), ProviderError> { // {{{
 		let expected = CellValue::String( GENDER_LIST[0].short.to_string() );
-		let mut sut = GenderProvider::new(&CREATION_DATA)?;
-
-		sut.reset( &vec![] )?;
+		let mut sut = GenderProvider::new( &ProviderCreationData::with_arguments( vec![] ) )?;
 
 		assert_eq!( expected, sut.provide()? );
 
@@ -109,9 +103,7 @@ mod tests {
 	#[test]
 	fn test_provide_returns_long_gender() -> Result<(), ProviderError> { // {{{
 		let expected = CellValue::String( GENDER_LIST[0].long.to_string() );
-		let mut sut = GenderProvider::new(&CREATION_DATA)?;
-
-		sut.reset( &vec![ Argument::Boolean(true) ] )?;
+		let mut sut = GenderProvider::new( &ProviderCreationData::with_arguments( vec![ Argument::Boolean(true) ] ) )?;
 
 		assert_eq!( expected, sut.provide()? );
 
@@ -119,25 +111,33 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_set_long_to_default_when_no_arguments_are_given() -> Result<(), ProviderError> { // {{{
-		let mut sut = GenderProvider::new(&CREATION_DATA)?;
+	fn test_new_should_set_long_to_default_when_no_arguments_are_given() -> Result<(), ProviderError> { // {{{
+		let sut = GenderProvider::new( &ProviderCreationData::with_arguments( vec![] ) )?;
 
-		sut.reset( &vec![ Argument::Boolean(true) ] )?;
+		assert_eq!( vec!["F", "M", "O"], sut.genders );
 
-		sut.reset( &vec![] )?;
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_truncate_long_genders_to_the_max_length() -> Result<(), ProviderError> { // {{{
+		let sut = GenderProvider::new( &ProviderCreationData {
+			kind: ColumnType::String(3),
+			..ProviderCreationData::with_arguments( vec![ Argument::Boolean(true) ] )
+		} )?;
 
-		assert_eq!(false, sut.long);
+		assert_eq!( vec!["FEM", "MAL", "OTH"], sut.genders );
 
 		Ok(())
 	} // }}}
 
 	#[test]
-	fn test_reset_should_return_error_when_no_boolean_is_given_to_long() -> Result<(), ProviderError> { // {{{
+	fn test_new_should_return_error_when_no_boolean_is_given_to_long() -> Result<(), ProviderError> { // {{{
 		let arg = "123".to_string();
 		let expected = Err( ProviderError::UnexpectedArgument( arg.clone(), "Boolean".to_string() ) );
-		let mut sut = GenderProvider::new(&CREATION_DATA)?;
 
-		let result = sut.reset( &vec![ Argument::String( arg.clone() ) ] );
+		let result = GenderProvider::new( &ProviderCreationData::with_arguments( vec![ Argument::String( arg.clone() ) ] ) )
+			.map( |sut| sut.genders );
 
 		assert_eq!(expected, result);
 
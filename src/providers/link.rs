@@ -0,0 +1,225 @@
+use rand::{
+	prelude::Rng,
+	RngCore,
+};
+use std::{
+	collections::HashSet,
+	fmt,
+};
+
+use crate::{
+	generator::CellValue,
+	parameter::{ Parameter, ParameterKind },
+	provider::{
+		ProviderCreationData,
+		ProviderImpl,
+		ProviderError,
+	},
+	parser::config::Argument,
+};
+
+pub const LINK_PROVIDER_NAME: &str = "link";
+
+/// The column a `#link()` provider takes its values from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LinkTarget {
+	pub table: String,
+	pub column: String,
+}
+
+impl LinkTarget { // {{{
+	/// Parses the argument of a `#link()` provider. The argument can be either
+	/// `"TableName.column_name"` or `"column_name"`. In the latter case the
+	/// table is `current_table`, which is required to be known.
+	pub fn parse(arguments: &[Argument], current_table: Option<&str>) -> Result<Self, ProviderError> {
+		let link = match arguments.first() {
+			Some( Argument::String(link) ) => link,
+			Some(arg) => return Err( ProviderError::UnexpectedArgument(
+				arg.to_string(),
+				"string".to_string(),
+			) ),
+			None => return Err( ProviderError::TooFewArguments(0, 1) ),
+		};
+
+		let (table, column) = match ( link.split_once('.'), current_table ) {
+			( Some( (table, column) ), _ ) => (table, column),
+			( None, Some(table) ) => (table, link.as_str()),
+			( None, None ) => return Err( ProviderError::UnexpectedArgument(
+				link.clone(),
+				"'TableName.column_name'".to_string(),
+			) ),
+		};
+
+		if table.is_empty() || column.is_empty() {
+			return Err( ProviderError::UnexpectedArgument(
+				link.clone(),
+				"'TableName.column_name' or 'column_name'".to_string(),
+			) );
+		}
+
+		Ok( Self {
+			table: table.to_string(),
+			column: column.to_string(),
+		} )
+	}
+} // }}}
+
+impl fmt::Display for LinkTarget { // {{{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}.{}", self.table, self.column)
+	}
+} // }}}
+
+pub struct LinkProvider {
+	rng: Box<dyn RngCore>,
+	items: Vec<CellValue>,
+}
+
+impl ProviderImpl for LinkProvider {
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		let target = LinkTarget::parse( &data.arguments, Some(&data.table) )?;
+		let generated_data = data.generated_data.borrow();
+
+		let column = generated_data.get(&target.table)
+			.and_then( |columns| columns.iter().find( |c| c.name == target.column ) )
+			.ok_or_else( || ProviderError::LinkTargetUnavailable( target.to_string() ) )?;
+
+		// null values can't be referenced, so only link to actual values
+		let items: Vec<CellValue> = column.data.iter()
+			.filter( |value| !matches!(value, CellValue::Null) )
+			.cloned()
+			.collect();
+
+		if items.is_empty() {
+			return Err( ProviderError::LinkTargetUnavailable( target.to_string() ) );
+		}
+
+		Ok( Self {
+			rng: data.rng(),
+			items,
+		} )
+	}
+
+	fn parameters() -> Vec<Parameter> {
+		vec![
+			Parameter::new("column", ParameterKind::String),
+		]
+	}
+
+	fn distinct_values(&self) -> Option<usize> {
+		let distinct: HashSet<String> = self.items.iter()
+			.map( |item| item.to_string() )
+			.collect();
+
+		Some( distinct.len() )
+	}
+
+	fn provide(&mut self) -> Result<CellValue, ProviderError> {
+		let selected = self.rng.gen_range( 0..self.items.len() );
+
+		Ok( self.items[selected].clone() )
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		generator::ColumnData,
+		parser::config::ColumnType,
+	};
+
+	fn creation_data(link: &str) -> ProviderCreationData { // {{{
+		let data = ProviderCreationData {
+			table: "Table".to_string(),
+			..ProviderCreationData::with_arguments( vec![ Argument::String( link.to_string() ) ] )
+		};
+
+		data.generated_data.borrow_mut().insert(
+			"Table".to_string(),
+			vec![ ColumnData {
+				name: "id".to_string(),
+				r#type: ColumnType::UnsignedInt,
+				data: vec![
+					CellValue::Null,
+					CellValue::UnsignedInt(2),
+					CellValue::UnsignedInt(3),
+				],
+				comment: None,
+			} ],
+		);
+
+		data
+	} // }}}
+
+	#[test]
+	fn test_parse_should_split_table_and_column() -> Result<(), ProviderError> { // {{{
+		let result = LinkTarget::parse(
+			&[ Argument::String( "Table.id".to_string() ) ],
+			Some("Other"),
+		)?;
+
+		assert_eq!( "Table", result.table );
+		assert_eq!( "id", result.column );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_default_to_the_current_table() -> Result<(), ProviderError> { // {{{
+		let result = LinkTarget::parse(
+			&[ Argument::String( "id".to_string() ) ],
+			Some("Current"),
+		)?;
+
+		assert_eq!( "Current", result.table );
+		assert_eq!( "id", result.column );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_when_no_string_is_given() -> Result<(), ProviderError> { // {{{
+		let expected = Err( ProviderError::UnexpectedArgument( "5".to_string(), "string".to_string() ) );
+
+		let result = LinkTarget::parse( &[ Argument::Int(5) ], None );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_provide_should_return_a_value_of_the_linked_column() -> Result<(), ProviderError> { // {{{
+		let mut sut = LinkProvider::new( &creation_data("Table.id") )?;
+
+		let result = sut.provide()?;
+
+		assert_eq!( CellValue::UnsignedInt(2), result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_link_to_the_current_table_by_default() -> Result<(), ProviderError> { // {{{
+		let mut sut = LinkProvider::new( &creation_data("id") )?;
+
+		let result = sut.provide()?;
+
+		assert_eq!( CellValue::UnsignedInt(2), result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_the_column_does_not_exist() -> Result<(), ProviderError> { // {{{
+		let expected = Err( ProviderError::LinkTargetUnavailable( "Table.unknown".to_string() ) );
+
+		let result = LinkProvider::new( &creation_data("Table.unknown") )
+			.map( |sut| sut.items );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+}
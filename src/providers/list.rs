@@ -0,0 +1,175 @@
+use rand::{
+	prelude::Rng,
+	RngCore,
+};
+use std::{
+	collections::HashSet,
+	path::Path,
+	fs,
+};
+
+use crate::{
+	generator::CellValue,
+	parameter::{ Parameter, ParameterKind },
+	provider::{
+		ProviderCreationData,
+		ProviderImpl,
+		ProviderError,
+	},
+	parser::config::{ Argument, ColumnType },
+};
+
+// bundled lists {{{
+pub const FIRST_NAMES_FILE: &str = "./sources/first_names.txt";
+pub const LAST_NAMES_FILE: &str = "./sources/last_names.txt";
+// }}}
+
+/// Reads the items in `file`, which contains one item per line. Empty lines
+/// are skipped.
+pub fn read_list(file: &Path) -> Result<Vec<String>, ProviderError> { // {{{
+	let items = fs::read_to_string(file)
+		.map_err( |e| ProviderError::Unknown( format!("{}: {}", file.display(), e) ) )?
+		.lines()
+		.filter( |item| !item.is_empty() )
+		.map( |item| item.to_string() )
+		.collect();
+
+	Ok(items)
+} // }}}
+
+/// Provides random items of a list file, like the bundled lists of first and
+/// last names. The same provider is registered once per bundled list, each
+/// with its list as the default file.
+pub struct ListProvider {
+	rng: Box<dyn RngCore>,
+	items: Vec<String>,
+	distinct_items: usize,
+}
+
+impl ListProvider { // {{{
+	/// Creates a provider of the items in the file given as argument, or in
+	/// `default_file` when no file is given
+	pub fn with_default_file(data: &ProviderCreationData, default_file: &str) -> Result<Self, ProviderError> { // {{{
+		let file = match data.arguments.first() {
+			Some( Argument::String(file) ) => file.as_str(),
+			Some(argument) => return Err( ProviderError::UnexpectedArgument(
+				argument.to_string(),
+				"the path of a list file".to_string(),
+			) ),
+			None => default_file,
+		};
+		let items = read_list( Path::new(file) )?
+			.iter()
+			.map( |item| data.truncate(item) )
+			.collect::< Vec<String> >();
+
+		if items.is_empty() {
+			return Err( ProviderError::UnexpectedArgument(
+				file.to_string(),
+				"a file with at least one item".to_string(),
+			) );
+		}
+
+		let distinct_items = items.iter()
+			.collect::< HashSet<&String> >()
+			.len();
+
+		Ok( Self {
+			rng: data.rng(),
+			items,
+			distinct_items,
+		} )
+	} // }}}
+} // }}}
+
+impl ProviderImpl for ListProvider {
+	/// Creates a provider of the items in the file given as argument, which
+	/// is required
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		match data.arguments.is_empty() {
+			true => Err( ProviderError::TooFewArguments(0, 1) ),
+			false => Self::with_default_file(data, ""),
+		}
+	}
+
+	fn parameters() -> Vec<Parameter> {
+		vec![ Parameter::new("file", ParameterKind::String) ]
+	}
+
+	fn distinct_values(&self) -> Option<usize> {
+		Some(self.distinct_items)
+	}
+
+	fn provided_type(&self) -> Option<ColumnType> {
+		Some( ColumnType::String(usize::MAX) )
+	}
+
+	fn provide(&mut self) -> Result<CellValue, ProviderError> {
+		let selected = self.rng.gen_range( 0..self.items.len() );
+
+		Ok(
+			CellValue::String( self.items[selected].clone() )
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use tempfile::tempdir;
+
+	use super::*;
+
+	fn creation_data(file: &Path) -> ProviderCreationData { // {{{
+		ProviderCreationData::with_arguments( vec![ Argument::String( file.display().to_string() ) ] )
+	} // }}}
+
+	#[test]
+	fn test_provide_should_return_the_first_item_of_the_file() -> Result<(), ProviderError> { // {{{
+		let directory = tempdir().unwrap();
+		let file = directory.path().join("names.txt");
+
+		fs::write(&file, "Name 1\n\nName 2\nName 1\n").unwrap();
+
+		let mut sut = ListProvider::new( &creation_data(&file) )?;
+
+		assert_eq!( CellValue::String( "Name 1".to_string() ), sut.provide()? );
+		assert_eq!( Some(2), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_with_default_file_should_read_the_default_file_when_no_file_is_given() -> Result<(), ProviderError> { // {{{
+		let directory = tempdir().unwrap();
+		let file = directory.path().join("names.txt");
+
+		fs::write(&file, "Name 1\nName 2\n").unwrap();
+
+		let mut sut = ListProvider::with_default_file( &ProviderCreationData::default(), &file.display().to_string() )?;
+
+		assert_eq!( CellValue::String( "Name 1".to_string() ), sut.provide()? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_for_empty_files() { // {{{
+		let directory = tempdir().unwrap();
+		let file = directory.path().join("names.txt");
+
+		fs::write(&file, "\n").unwrap();
+
+		let result = ListProvider::new( &creation_data(&file) );
+
+		assert!( matches!( result, Err( ProviderError::UnexpectedArgument(_, _) ) ) );
+	} // }}}
+
+	#[test]
+	fn test_read_list_should_read_the_bundled_lists() -> Result<(), ProviderError> { // {{{
+		for file in [FIRST_NAMES_FILE, LAST_NAMES_FILE] {
+			assert!( !read_list( Path::new(file) )?.is_empty() );
+		}
+
+		Ok(())
+	} // }}}
+}
@@ -1,5 +1,14 @@
-pub mod first_name;
+pub mod date;
+pub mod date_time;
+pub mod decimal;
+pub mod enumeration;
 pub mod gender;
+pub mod link;
+pub mod list;
+pub mod name;
 pub mod number;
 pub mod random;
 pub mod row;
+pub mod temporal;
+pub mod time;
+pub mod weighted;
@@ -0,0 +1,165 @@
+use rand::{
+	prelude::Rng,
+	RngCore,
+};
+use std::{
+	collections::HashSet,
+	path::Path,
+};
+
+use crate::{
+	generator::CellValue,
+	parameter::{ Parameter, ParameterKind },
+	provider::{
+		ProviderCreationData,
+		ProviderImpl,
+		ProviderError,
+	},
+	parser::config::{ Argument, ColumnType },
+	providers::list::{
+		read_list,
+		FIRST_NAMES_FILE,
+		LAST_NAMES_FILE,
+	},
+};
+
+// name formats {{{
+/// `Clementine Baglow`
+pub const FORMAT_FIRST_LAST: &str = "first_last";
+/// `Baglow, Clementine`
+pub const FORMAT_LAST_COMMA_FIRST: &str = "last_comma_first";
+/// `Clementine J. Baglow`
+pub const FORMAT_FIRST_INITIAL_LAST: &str = "first_initial_last";
+// }}}
+
+/// The way the parts of a full name are combined
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NameFormat {
+	FirstLast,
+	LastCommaFirst,
+	FirstInitialLast,
+}
+
+impl NameFormat { // {{{
+	fn parse(argument: &Argument) -> Result<Self, ProviderError> {
+		match argument {
+			Argument::String(format) if format == FORMAT_FIRST_LAST => Ok(Self::FirstLast),
+			Argument::String(format) if format == FORMAT_LAST_COMMA_FIRST => Ok(Self::LastCommaFirst),
+			Argument::String(format) if format == FORMAT_FIRST_INITIAL_LAST => Ok(Self::FirstInitialLast),
+
+			argument => Err( ProviderError::UnexpectedArgument(
+				argument.to_string(),
+				format!(
+					"one of: {}, {}, {}",
+					FORMAT_FIRST_LAST,
+					FORMAT_LAST_COMMA_FIRST,
+					FORMAT_FIRST_INITIAL_LAST,
+				),
+			) ),
+		}
+	}
+
+	/// Combines the parts of a name. `initial` is only used by formats with a
+	/// middle initial.
+	fn format(&self, first: &str, initial: char, last: &str) -> String {
+		match self {
+			Self::FirstLast => format!("{} {}", first, last),
+			Self::LastCommaFirst => format!("{}, {}", last, first),
+			Self::FirstInitialLast => format!("{} {}. {}", first, initial, last),
+		}
+	}
+} // }}}
+
+/// Provides full names, combined from the bundled lists of first and last
+/// names.
+pub struct NameProvider {
+	rng: Box<dyn RngCore>,
+	first_names: Vec<String>,
+	last_names: Vec<String>,
+	format: NameFormat,
+	/// The maximum length of the column, the names are shortened to fit it
+	max_length: Option<usize>,
+}
+
+impl ProviderImpl for NameProvider {
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		let format = match data.arguments.first() {
+			Some(format) => NameFormat::parse(format)?,
+			None => NameFormat::FirstLast,
+		};
+
+		Ok( Self {
+			rng: data.rng(),
+			first_names: read_list( Path::new(FIRST_NAMES_FILE) )?,
+			last_names: read_list( Path::new(LAST_NAMES_FILE) )?,
+			format,
+			max_length: data.kind.max_length(),
+		} )
+	}
+
+	fn parameters() -> Vec<Parameter> {
+		vec![
+			Parameter::new("format", ParameterKind::String)
+				.with_default( Argument::String( FORMAT_FIRST_LAST.to_string() ) ),
+		]
+	}
+
+	fn distinct_values(&self) -> Option<usize> {
+		// shortened names can be equal, which isn't known before generating
+		if self.max_length.is_some() {
+			return None;
+		}
+
+		let first_names = self.first_names.iter().collect::< HashSet<&String> >().len();
+		let last_names = self.last_names.iter().collect::< HashSet<&String> >().len();
+		let initials = match self.format {
+			NameFormat::FirstInitialLast => self.first_names.iter()
+				.filter_map( |name| name.chars().next() )
+				.collect::< HashSet<char> >()
+				.len(),
+			_ => 1,
+		};
+
+		Some( first_names.saturating_mul(last_names).saturating_mul(initials) )
+	}
+
+	fn provided_type(&self) -> Option<ColumnType> {
+		Some( ColumnType::String(usize::MAX) )
+	}
+
+	fn provide(&mut self) -> Result<CellValue, ProviderError> {
+		let first = &self.first_names[ self.rng.gen_range( 0..self.first_names.len() ) ];
+		let last = &self.last_names[ self.rng.gen_range( 0..self.last_names.len() ) ];
+		// take the initial of another first name, so common initials are
+		// common as well
+		let initial = self.first_names[ self.rng.gen_range( 0..self.first_names.len() ) ]
+			.chars()
+			.next()
+			.unwrap_or('A');
+		let name = self.format.format(first, initial, last);
+
+		Ok( CellValue::String( match self.max_length {
+			Some(max_length) => name.chars().take(max_length).collect(),
+			None => name,
+		} ) )
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_format_should_combine_the_parts_of_the_name() { // {{{
+		assert_eq!( "Orran O'Markey", NameFormat::FirstLast.format("Orran", 'J', "O'Markey") );
+		assert_eq!( "O'Markey, Orran", NameFormat::LastCommaFirst.format("Orran", 'J', "O'Markey") );
+		assert_eq!( "Orran J. O'Markey", NameFormat::FirstInitialLast.format("Orran", 'J', "O'Markey") );
+	} // }}}
+
+	#[test]
+	fn test_parse_should_return_error_for_unknown_formats() { // {{{
+		let result = NameFormat::parse( &Argument::String( "last_first".to_string() ) );
+
+		assert!( matches!( result, Err( ProviderError::UnexpectedArgument(_, _) ) ) );
+	} // }}}
+}
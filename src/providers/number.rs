@@ -2,29 +2,30 @@ use rand::{
 	prelude::Rng,
 	RngCore
 };
-#[cfg(test)]
-use rand::rngs::mock::StepRng;
 
 use crate::{
+	distribution::Distribution,
 	generator::CellValue,
+	parameter::{ Parameter, ParameterKind },
 	provider::{
 		ProviderCreationData,
 		ProviderImpl,
 		ProviderError,
 	},
-	parser::config::Argument,
+	parser::config::{ Argument, ColumnType },
 };
 
 pub struct NumberProvider {
 	rng: Box<dyn RngCore>,
 	min: i64,
 	max: i64,
+	distribution: Distribution,
 }
 
 impl NumberProvider {
-	fn parse_value_from_arg(&self, arg: &Argument) -> Result<i64, ProviderError> {
+	fn parse_value_from_arg(arg: &Argument) -> Result<i64, ProviderError> {
 		if let Argument::Int(value) = arg {
-			return Ok( value.clone() );
+			return Ok(*value);
 		}
 
 		Err( ProviderError::UnexpectedArgument(
@@ -35,45 +36,57 @@ impl NumberProvider {
 }
 
 impl ProviderImpl for NumberProvider {
-	#[cfg( not(test) )]
-	fn new(_data: &ProviderCreationData) -> Result<Self, ProviderError> {
-		Ok( NumberProvider {
-			rng: Box::new( rand::thread_rng() ),
-			min: 0,
-			max: i64::MAX,
-		} )
-	}
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		let min = match data.arguments.first() {
+			Some(min) => Self::parse_value_from_arg(min)?,
+			None => 0,
+		};
+		let max = match data.arguments.get(1) {
+			Some(max) => Self::parse_value_from_arg(max)?,
+			None => i64::MAX,
+		};
+
+		let distribution = Distribution::new(
+			data.arguments.get(2),
+			data.arguments.get(3..).unwrap_or_default(),
+			min as f64,
+			max as f64,
+		)?;
 
-	#[cfg(test)]
-	fn new(_data: &ProviderCreationData) -> Result<Self, ProviderError> {
 		Ok( Self {
-			rng: Box::new( StepRng::new(0, 1) ),
-			min: 0,
-			max: i64::MAX,
+			rng: data.rng(),
+			min,
+			max,
+			distribution,
 		} )
 	}
 
-	fn reset(&mut self, arguments: &Vec<Argument>) -> Result<(), ProviderError> {
-		if arguments.is_empty() {
-			self.min = 0;
-			self.max = i64::MAX;
-
-			return Ok(());
-		}
+	fn parameters() -> Vec<Parameter> {
+		[
+			vec![
+				Parameter::new("min", ParameterKind::Int).with_default( Argument::Int(0) ),
+				Parameter::new("max", ParameterKind::Int).with_default( Argument::Int(i64::MAX) ),
+			],
+			Distribution::parameters(),
+		].concat()
+	}
 
-		if let Some(min) = arguments.get(0) {
-			self.min = self.parse_value_from_arg(min)?;
-		}
+	fn distinct_values(&self) -> Option<usize> {
+		let count = (self.max as i128 - self.min as i128 + 1).max(0);
 
-		if let Some(max) = arguments.get(1) {
-			self.max = self.parse_value_from_arg(max)?;
-		}
+		Some( usize::try_from(count).unwrap_or(usize::MAX) )
+	}
 
-		Ok(())
+	fn provided_type(&self) -> Option<ColumnType> {
+		Some(ColumnType::Int)
 	}
 
 	fn provide(&mut self) -> Result<CellValue, ProviderError> {
-		let value = self.rng.gen_range(self.min..=self.max);
+		let value = match self.distribution.sample( &mut self.rng ) {
+			// casting saturates, so values outside of i64 end up at the bounds
+			Some(value) => (value.round() as i64).clamp(self.min, self.max),
+			None => self.rng.gen_range(self.min..=self.max),
+		};
 
 		Ok( CellValue::Int(value) )
 	}
@@ -83,12 +96,9 @@ impl ProviderImpl for NumberProvider {
 mod tests {
 	use super::*;
 
-	const CREATION_DATA: ProviderCreationData = ProviderCreationData { row_count: 1000 };
-
 	#[test]
 	fn test_provide_should_return_a_number() -> Result<(), ProviderError> { // {{{
-		let mut sut = NumberProvider::new(&CREATION_DATA)?;
-		sut.reset( &vec![] )?;
+		let mut sut = NumberProvider::new( &ProviderCreationData::with_arguments( vec![] ) )?;
 
 		let result = sut.provide()?;
 
@@ -98,11 +108,19 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_set_minimum_to_10() -> Result<(), ProviderError> { // {{{
+	fn test_distinct_values_should_include_minimum_and_maximum() -> Result<(), ProviderError> { // {{{
+		let sut = NumberProvider::new( &ProviderCreationData::with_arguments( vec![ Argument::Int(0), Argument::Int(10) ] ) )?;
+
+		assert_eq!( Some(11), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_set_minimum_to_10() -> Result<(), ProviderError> { // {{{
 		let expected = 10;
-		let mut sut = NumberProvider::new(&CREATION_DATA)?;
 
-		sut.reset( &vec![ Argument::Int(expected) ] )?;
+		let sut = NumberProvider::new( &ProviderCreationData::with_arguments( vec![ Argument::Int(expected) ] ) )?;
 
 		assert_eq!(expected, sut.min);
 
@@ -110,11 +128,10 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_set_maximum_to_10() -> Result<(), ProviderError> { // {{{
+	fn test_new_should_set_maximum_to_10() -> Result<(), ProviderError> { // {{{
 		let expected = 10;
-		let mut sut = NumberProvider::new(&CREATION_DATA)?;
 
-		sut.reset( &vec![ Argument::Int(5), Argument::Int(expected) ] )?;
+		let sut = NumberProvider::new( &ProviderCreationData::with_arguments( vec![ Argument::Int(5), Argument::Int(expected) ] ) )?;
 
 		assert_eq!(expected, sut.max);
 
@@ -122,11 +139,10 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_set_minimum_to_15_when_maximum_is_also_set() -> Result<(), ProviderError> { // {{{
+	fn test_new_should_set_minimum_to_15_when_maximum_is_also_set() -> Result<(), ProviderError> { // {{{
 		let expected = 15;
-		let mut sut = NumberProvider::new(&CREATION_DATA)?;
 
-		sut.reset( &vec![ Argument::Int(expected), Argument::Int(20) ] )?;
+		let sut = NumberProvider::new( &ProviderCreationData::with_arguments( vec![ Argument::Int(expected), Argument::Int(20) ] ) )?;
 
 		assert_eq!(expected, sut.min);
 
@@ -134,12 +150,12 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_return_error_when_no_number_is_given_to_minimum() -> Result<(), ProviderError> { // {{{
+	fn test_new_should_return_error_when_no_number_is_given_to_minimum() -> Result<(), ProviderError> { // {{{
 		let arg = "abc".to_string();
 		let expected = Err( ProviderError::UnexpectedArgument( arg.clone(), "int".to_string() ) );
-		let mut sut = NumberProvider::new(&CREATION_DATA)?;
 
-		let result = sut.reset( &vec![ Argument::String(arg) ] );
+		let result = NumberProvider::new( &ProviderCreationData::with_arguments( vec![ Argument::String(arg) ] ) )
+			.map( |sut| sut.min );
 
 		assert_eq!(expected, result);
 
@@ -147,15 +163,15 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_return_error_when_no_number_is_given_to_maximum() -> Result<(), ProviderError> { // {{{
+	fn test_new_should_return_error_when_no_number_is_given_to_maximum() -> Result<(), ProviderError> { // {{{
 		let arg = "abc".to_string();
 		let expected = Err( ProviderError::UnexpectedArgument( arg.clone(), "int".to_string() ) );
-		let mut sut = NumberProvider::new(&CREATION_DATA)?;
 
-		let result = sut.reset( &vec![
+		let result = NumberProvider::new( &ProviderCreationData::with_arguments( vec![
 			Argument::Int(5),
 			Argument::String(arg),
-		] );
+		] ) )
+			.map( |sut| sut.max );
 
 		assert_eq!(expected, result);
 
@@ -163,25 +179,45 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_set_min_to_default_when_no_arguments_are_given() -> Result<(), ProviderError> { // {{{
-		let mut sut = NumberProvider::new(&CREATION_DATA)?;
-
-		sut.reset( &vec![ Argument::Int(5), Argument::Int(10) ] )?;
-
-		sut.reset( &vec![] )?;
+	fn test_new_should_set_defaults_when_no_arguments_are_given() -> Result<(), ProviderError> { // {{{
+		let sut = NumberProvider::new( &ProviderCreationData::with_arguments( vec![] ) )?;
 
 		assert_eq!(0, sut.min);
+		assert_eq!(i64::MAX, sut.max);
 
 		Ok(())
 	} // }}}
 
 	#[test]
-	fn test_reset_should_set_max_to_default_when_no_arguments_are_given() -> Result<(), ProviderError> { // {{{
-		let mut sut = NumberProvider::new(&CREATION_DATA)?;
+	fn test_provide_should_clamp_values_of_a_distribution_to_the_range() -> Result<(), ProviderError> { // {{{
+		let mut sut = NumberProvider::new( &ProviderCreationData::with_arguments( vec![
+			Argument::Int(18),
+			Argument::Int(99),
+			Argument::String( "normal".to_string() ),
+			Argument::Int(-1000),
+			Argument::Int(1),
+		] ) )?;
 
-		sut.reset( &vec![ Argument::Int(5), Argument::Int(10) ] )?;
+		assert_eq!( CellValue::Int(18), sut.provide()? );
 
-		sut.reset( &vec![] )?;
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_for_invalid_distribution_arguments() { // {{{
+		let result = NumberProvider::new( &ProviderCreationData::with_arguments( vec![
+			Argument::Int(0),
+			Argument::Int(10),
+			Argument::String( "exponential".to_string() ),
+			Argument::Int(0),
+		] ) );
+
+		assert!( matches!( result.err(), Some( ProviderError::InvalidDistribution(_) ) ) );
+	} // }}}
+
+	#[test]
+	fn test_new_should_set_max_to_default_when_only_min_is_given() -> Result<(), ProviderError> { // {{{
+		let sut = NumberProvider::new( &ProviderCreationData::with_arguments( vec![ Argument::Int(5) ] ) )?;
 
 		assert_eq!(i64::MAX, sut.max);
 
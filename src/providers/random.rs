@@ -3,43 +3,75 @@ use rand::{
 	prelude::Rng,
 	RngCore,
 };
-#[cfg(test)]
-use rand::rngs::mock::StepRng;
+use std::collections::HashSet;
 
 use crate::{
+	parameter::{ Parameter, ParameterKind },
 	provider::{
 		ProviderCreationData,
 		ProviderImpl,
 		ProviderError,
 	},
 	generator::CellValue,
-	parser::config::Argument,
+	parser::config::ColumnType,
 };
 
+/// Returns the type shared by all `items`, or `None` when they differ. Used as
+/// the provided type of providers choosing from a list of values.
+pub fn common_kind(items: &[CellValue]) -> Option<ColumnType> { // {{{
+	// strings are shortened to fit the column, so their length doesn't
+	// matter
+	let mut kinds = items.iter()
+		.filter_map(CellValue::kind)
+		.map( |kind| match kind {
+			ColumnType::String(_) => ColumnType::String(usize::MAX),
+			kind => kind,
+		} );
+	let first = kinds.next()?;
+
+	// items of different types are checked while generating
+	kinds.all( |kind| kind == first )
+		.then_some(first)
+} // }}}
+
 pub struct RandomProvider {
 	rng: Box<dyn RngCore>,
 	items: Vec<CellValue>,
 }
 
 impl ProviderImpl for RandomProvider {
-	fn new(_data: &ProviderCreationData) -> Result<Self, ProviderError> {
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		if data.arguments.len() < 2 {
+			return Err( ProviderError::TooFewArguments( data.arguments.len(), 2 ) );
+		}
+
 		Ok( Self {
-			#[cfg( not(test) )] rng: Box::new( rand::thread_rng() ),
-			#[cfg(test)] rng: Box::new( StepRng::new(0, 1) ),
-			items: vec![],
+			rng: data.rng(),
+			items: data.arguments.iter()
+				.map( |argument| match CellValue::from(argument) {
+					CellValue::String(value) => CellValue::String( data.truncate(&value) ),
+					value => value,
+				} )
+				.collect(),
 		} )
 	}
 
-	fn reset(&mut self, arguments: &Vec<Argument>) -> Result<(), ProviderError> {
-		if arguments.len() < 2 {
-			return Err( ProviderError::TooFewArguments( arguments.len(), 2 ) );
-		}
+	fn parameters() -> Vec<Parameter> {
+		vec![
+			Parameter::new("options", ParameterKind::Any).variadic(),
+		]
+	}
 
-		self.items = arguments.iter()
-			.map(CellValue::from)
+	fn distinct_values(&self) -> Option<usize> {
+		let distinct: HashSet<String> = self.items.iter()
+			.map( |item| item.to_string() )
 			.collect();
 
-		Ok(())
+		Some( distinct.len() )
+	}
+
+	fn provided_type(&self) -> Option<ColumnType> {
+		common_kind(&self.items)
 	}
 
 	fn provide(&mut self) -> Result<CellValue, ProviderError> {
@@ -52,8 +84,8 @@ impl ProviderImpl for RandomProvider {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::parser::config::{ Argument, ColumnType };
 
-	const CREATION_DATA: ProviderCreationData = ProviderCreationData { row_count: 1000 };
 	lazy_static! {
 		static ref ITEMS: Vec<Argument> = vec![
 			Argument::String( "Item 1".to_string() ),
@@ -66,9 +98,7 @@ mod tests {
 
 	#[test]
 	fn test_provide_should_return_the_first_item() -> Result<(), ProviderError> { // {{{
-		let mut sut = RandomProvider::new(&CREATION_DATA)?;
-
-		sut.reset(&ITEMS)?;
+		let mut sut = RandomProvider::new( &ProviderCreationData::with_arguments( ITEMS.clone() ) )?;
 
 		let result = sut.provide()?;
 
@@ -81,12 +111,36 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_give_an_error_when_too_few_arguments_are_given() -> Result<(), ProviderError> { // {{{
-		let mut sut = RandomProvider::new(&CREATION_DATA)?;
+	fn test_distinct_values_should_not_count_duplicate_items() -> Result<(), ProviderError> { // {{{
+		let sut = RandomProvider::new( &ProviderCreationData::with_arguments( vec![
+			Argument::String( "Item 1".to_string() ),
+			Argument::String( "Item 1".to_string() ),
+			Argument::String( "Item 2".to_string() ),
+		] ) )?;
+
+		assert_eq!( Some(2), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_distinct_values_should_count_items_that_are_equal_after_truncating_once() -> Result<(), ProviderError> { // {{{
+		let sut = RandomProvider::new( &ProviderCreationData {
+			kind: ColumnType::String(4),
+			..ProviderCreationData::with_arguments( ITEMS.clone() )
+		} )?;
 
-		let result = sut.reset(&vec![
+		assert_eq!( Some(1), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_give_an_error_when_too_few_arguments_are_given() -> Result<(), ProviderError> { // {{{
+		let result = RandomProvider::new( &ProviderCreationData::with_arguments( vec![
 			Argument::String( "Item 1".to_string() ),
-		]);
+		] ) )
+			.map( |sut| sut.items );
 
 		assert_eq!(result, Err( ProviderError::TooFewArguments(1, 2) ));
 
@@ -94,15 +148,34 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_should_not_give_an_error_when_just_enough_arguments_are_given() -> Result<(), ProviderError> { // {{{
-		let mut sut = RandomProvider::new(&CREATION_DATA)?;
-
-		let result = sut.reset(&vec![
+	fn test_new_should_not_give_an_error_when_just_enough_arguments_are_given() -> Result<(), ProviderError> { // {{{
+		let result = RandomProvider::new( &ProviderCreationData::with_arguments( vec![
 			Argument::String( "Item 1".to_string() ),
 			Argument::String( "Item 2".to_string() ),
-		]);
+		] ) );
+
+		assert!( result.is_ok() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_provided_type_should_return_the_type_shared_by_all_items() -> Result<(), ProviderError> { // {{{
+		let sut = RandomProvider::new( &ProviderCreationData::with_arguments( ITEMS.clone() ) )?;
+
+		assert_eq!( Some( ColumnType::String(usize::MAX) ), sut.provided_type() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_provided_type_should_return_none_for_items_of_different_types() -> Result<(), ProviderError> { // {{{
+		let sut = RandomProvider::new( &ProviderCreationData::with_arguments( vec![
+			Argument::Int(1),
+			Argument::String( "Item 1".to_string() ),
+		] ) )?;
 
-		assert_eq!( result, Ok(()) );
+		assert_eq!( None, sut.provided_type() );
 
 		Ok(())
 	} // }}}
@@ -5,7 +5,7 @@ use crate::{
 		ProviderImpl,
 		ProviderError,
 	},
-	parser::config::Argument,
+	parser::config::ColumnType,
 };
 
 pub struct RowProvider {
@@ -19,10 +19,8 @@ impl ProviderImpl for RowProvider {
 		} )
 	}
 
-	fn reset(&mut self, _arguments: &Vec<Argument>) -> Result<(), ProviderError> {
-		self.curr_count = 1;
-
-		Ok(())
+	fn provided_type(&self) -> Option<ColumnType> {
+		Some(ColumnType::UnsignedInt)
 	}
 
 	fn provide(&mut self) -> Result<CellValue, ProviderError> {
@@ -36,15 +34,13 @@ impl ProviderImpl for RowProvider {
 
 #[cfg(test)]
 mod tests {
-	use super::*;
+	use crate::registry::registrars::register_providers;
 
-	const CREATION_DATA: ProviderCreationData = ProviderCreationData { row_count: 1000 };
+	use super::*;
 
 	#[test]
 	fn test_provide_should_return_1() -> Result<(), ProviderError> { // {{{
-		let mut sut = RowProvider::new(&CREATION_DATA)?;
-
-		sut.reset( &vec![] )?;
+		let mut sut = RowProvider::new( &ProviderCreationData::default() )?;
 
 		let result = sut.provide()?;
 
@@ -55,9 +51,7 @@ mod tests {
 
 	#[test]
 	fn test_provide_should_return_5() -> Result<(), ProviderError> { // {{{
-		let mut sut = RowProvider::new(&CREATION_DATA)?;
-
-		sut.reset( &vec![] )?;
+		let mut sut = RowProvider::new( &ProviderCreationData::default() )?;
 
 		// run 4 times, so the next provide should return "5"
 		for _ in 0..4 {
@@ -72,21 +66,17 @@ mod tests {
 	} // }}}
 
 	#[test]
-	fn test_reset_resets_counter() -> Result<(), ProviderError> { // {{{
-		let mut sut = RowProvider::new(&CREATION_DATA)?;
-
-		sut.reset( &vec![] )?;
+	fn test_create_should_give_every_provider_its_own_counter() -> anyhow::Result<()> { // {{{
+		let registry = register_providers()?;
+		let mut first = registry.create( "row", &ProviderCreationData::default() )?;
 
-		// run 4 times, so the next provide should return "5"
-		for _ in 0..4 {
-			sut.provide()?;
-		}
+		first.provide()?;
+		first.provide()?;
 
-		sut.reset( &vec![] )?;
+		let mut second = registry.create( "row", &ProviderCreationData::default() )?;
 
-		let result = sut.provide()?;
-
-		assert_eq!( CellValue::UnsignedInt(1), result );
+		assert_eq!( CellValue::UnsignedInt(1), second.provide()? );
+		assert_eq!( CellValue::UnsignedInt(3), first.provide()? );
 
 		Ok(())
 	} // }}}
@@ -0,0 +1,192 @@
+use chrono::TimeDelta;
+use rand::{
+	prelude::Rng,
+	RngCore,
+};
+use std::ops::{ Add, Sub };
+
+use crate::{
+	generator::CellValue,
+	parameter::{ Parameter, ParameterKind },
+	provider::{
+		ProviderCreationData,
+		ProviderImpl,
+		ProviderError,
+	},
+	parser::config::{ Argument, ColumnType },
+};
+
+/// A type of dates or times a [`TemporalProvider`] provides. Only describes
+/// how values are written and how far apart they are, the range is handled by
+/// the provider.
+pub trait Temporal: Copy + Ord + Add<TimeDelta, Output = Self> + Sub<Output = TimeDelta> + 'static {
+	/// The formats accepted for the minimum and maximum. The first one is used
+	/// to write values in errors.
+	const FORMATS: &'static [&'static str];
+	/// The format of the minimum and maximum, as it's shown in errors
+	const FORMAT_DESCRIPTION: &'static str;
+	/// What a value is called in errors, like `a date`
+	const DESCRIPTION: &'static str;
+	const DEFAULT_MIN: &'static str;
+	const DEFAULT_MAX: &'static str;
+	/// The amount of seconds between two values that follow each other
+	const STEP_SECONDS: i64;
+
+	fn parse(value: &str, format: &str) -> Option<Self>;
+	fn format(&self, format: &str) -> String;
+	fn column_type() -> ColumnType;
+	fn cell_value(self) -> CellValue;
+}
+
+/// Provides a random date or time between a minimum and maximum, which are
+/// given as strings in one of the formats of `T`.
+pub struct TemporalProvider<T: Temporal> {
+	rng: Box<dyn RngCore>,
+	min: T,
+	/// The amount of steps between the minimum and the maximum
+	steps: i64,
+}
+
+impl<T: Temporal> TemporalProvider<T> { // {{{
+	fn parse_value(value: &str) -> Option<T> {
+		T::FORMATS.iter()
+			.find_map( |format| T::parse(value, format) )
+	}
+
+	fn parse_value_from_arg(arg: &Argument) -> Result<T, ProviderError> {
+		if let Argument::String(value) = arg {
+			if let Some(value) = Self::parse_value(value) {
+				return Ok(value);
+			}
+		}
+
+		Err( ProviderError::UnexpectedArgument(
+			arg.to_string(),
+			format!("{} in the format '{}'", T::DESCRIPTION, T::FORMAT_DESCRIPTION),
+		) )
+	}
+
+	fn parse_default(value: &str) -> T {
+		Self::parse_value(value)
+			.expect("the defaults should be in an accepted format")
+	}
+} // }}}
+
+impl<T: Temporal> ProviderImpl for TemporalProvider<T> {
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		let min = match data.arguments.first() {
+			Some(min) => Self::parse_value_from_arg(min)?,
+			None => Self::parse_default(T::DEFAULT_MIN),
+		};
+		let max = match data.arguments.get(1) {
+			Some(max) => Self::parse_value_from_arg(max)?,
+			None => Self::parse_default(T::DEFAULT_MAX),
+		};
+
+		if max < min {
+			return Err( ProviderError::UnexpectedArgument(
+				max.format( T::FORMATS[0] ),
+				format!( "{} not before '{}'", T::DESCRIPTION, min.format( T::FORMATS[0] ) ),
+			) );
+		}
+
+		Ok( Self {
+			rng: data.rng(),
+			min,
+			steps: (max - min).num_seconds() / T::STEP_SECONDS,
+		} )
+	}
+
+	fn parameters() -> Vec<Parameter> {
+		vec![
+			Parameter::new("min", ParameterKind::String).with_default( Argument::String( T::DEFAULT_MIN.to_string() ) ),
+			Parameter::new("max", ParameterKind::String).with_default( Argument::String( T::DEFAULT_MAX.to_string() ) ),
+		]
+	}
+
+	fn distinct_values(&self) -> Option<usize> {
+		Some( usize::try_from(self.steps + 1).unwrap_or(usize::MAX) )
+	}
+
+	fn provided_type(&self) -> Option<ColumnType> {
+		Some( T::column_type() )
+	}
+
+	fn provide(&mut self) -> Result<CellValue, ProviderError> {
+		let offset = self.rng.gen_range(0..=self.steps);
+
+		Ok( ( self.min + TimeDelta::seconds(offset * T::STEP_SECONDS) ).cell_value() )
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use super::*;
+
+	type Sut = TemporalProvider<NaiveDate>;
+
+	/// Creates the data of a provider with `arguments` as strings
+	fn creation_data(arguments: &[&str]) -> ProviderCreationData { // {{{
+		ProviderCreationData::with_arguments(
+			arguments.iter()
+				.map( |arg| Argument::String( arg.to_string() ) )
+				.collect()
+		)
+	} // }}}
+
+	#[test]
+	fn test_provide_should_return_the_minimum() -> Result<(), ProviderError> { // {{{
+		let mut sut = Sut::new( &creation_data( &["2020-02-28", "2020-03-01"] ) )?;
+
+		let result = sut.provide()?;
+
+		assert_eq!( CellValue::Date( NaiveDate::from_ymd_opt(2020, 2, 28).unwrap() ), result );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_use_the_defaults_when_no_arguments_are_given() -> Result<(), ProviderError> { // {{{
+		let sut = Sut::new( &creation_data(&[]) )?;
+
+		assert_eq!( NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(), sut.min );
+		assert_eq!(
+			NaiveDate::from_ymd_opt(2099, 12, 31).unwrap(),
+			sut.min + TimeDelta::days(sut.steps),
+		);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_the_value_is_invalid() -> Result<(), ProviderError> { // {{{
+		let expected = Err( ProviderError::UnexpectedArgument(
+			"2020-13-01".to_string(),
+			"a date in the format 'YYYY-MM-DD'".to_string(),
+		) );
+
+		let result = Sut::new( &creation_data( &["2020-13-01"] ) )
+			.map( |sut| sut.min );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_the_maximum_is_before_the_minimum() -> Result<(), ProviderError> { // {{{
+		let expected = Err( ProviderError::UnexpectedArgument(
+			"2019-12-31".to_string(),
+			"a date not before '2020-01-01'".to_string(),
+		) );
+
+		let result = Sut::new( &creation_data( &["2020-01-01", "2019-12-31"] ) )
+			.map( |sut| sut.min );
+
+		assert_eq!(expected, result);
+
+		Ok(())
+	} // }}}
+}
@@ -0,0 +1,52 @@
+use chrono::NaiveTime;
+
+use crate::{
+	generator::{ CellValue, TIME_FORMAT },
+	parser::config::ColumnType,
+	providers::temporal::{ Temporal, TemporalProvider },
+};
+
+/// Provides a random time of day between a minimum and maximum, which are
+/// given as strings in the format `HH:MM:SS`.
+pub type TimeProvider = TemporalProvider<NaiveTime>;
+
+impl Temporal for NaiveTime {
+	const FORMATS: &'static [&'static str] = &[TIME_FORMAT];
+	const FORMAT_DESCRIPTION: &'static str = "HH:MM:SS";
+	const DESCRIPTION: &'static str = "a time";
+	const DEFAULT_MIN: &'static str = "00:00:00";
+	const DEFAULT_MAX: &'static str = "23:59:59";
+	const STEP_SECONDS: i64 = 1;
+
+	fn parse(value: &str, format: &str) -> Option<Self> {
+		Self::parse_from_str(value, format).ok()
+	}
+
+	fn format(&self, format: &str) -> String {
+		NaiveTime::format(self, format).to_string()
+	}
+
+	fn column_type() -> ColumnType {
+		ColumnType::Time
+	}
+
+	fn cell_value(self) -> CellValue {
+		CellValue::Time(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::provider::{ ProviderCreationData, ProviderImpl, ProviderError };
+
+	use super::*;
+
+	#[test]
+	fn test_distinct_values_should_count_every_second() -> Result<(), ProviderError> { // {{{
+		let sut = TimeProvider::new( &ProviderCreationData::default() )?;
+
+		assert_eq!( Some(24 * 60 * 60), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+}
@@ -0,0 +1,158 @@
+use rand::RngCore;
+use std::collections::HashSet;
+
+use crate::{
+	alias::AliasTable,
+	parameter::{ Parameter, ParameterKind },
+	provider::{
+		ProviderCreationData,
+		ProviderImpl,
+		ProviderError,
+	},
+	providers::random::common_kind,
+	generator::CellValue,
+	parser::config::{ Argument, ColumnType },
+};
+
+/// Chooses one of its values, each with a chance proportional to the weight
+/// given after it, like `#weighted("active", 90, "suspended", 9, "closed", 1)`.
+pub struct WeightedProvider {
+	rng: Box<dyn RngCore>,
+	items: Vec<CellValue>,
+	weights: Vec<f64>,
+	table: AliasTable,
+}
+
+impl ProviderImpl for WeightedProvider {
+	fn new(data: &ProviderCreationData) -> Result<Self, ProviderError> {
+		if data.arguments.len() < 2 {
+			return Err( ProviderError::TooFewArguments( data.arguments.len(), 2 ) );
+		}
+
+		let mut items = Vec::with_capacity( data.arguments.len() / 2 );
+		let mut weights = Vec::with_capacity( data.arguments.len() / 2 );
+
+		for pair in data.arguments.chunks(2) {
+			let item = match CellValue::from(&pair[0]) {
+				CellValue::String(value) => CellValue::String( data.truncate(&value) ),
+				value => value,
+			};
+			let weight = match pair.get(1) {
+				Some( Argument::Int(weight) ) => *weight as f64,
+				Some( Argument::Float(weight) ) => *weight,
+				Some(argument) => return Err( ProviderError::UnexpectedArgument(
+					argument.to_string(),
+					format!("the weight of '{}' as an int or float", pair[0]),
+				) ),
+				None => return Err( ProviderError::InvalidWeights( format!(
+					"'{}' has no weight, every value has to be followed by its weight",
+					pair[0],
+				) ) ),
+			};
+
+			if weight < 0.0 || !weight.is_finite() {
+				return Err( ProviderError::InvalidWeights( format!(
+					"the weight of '{}' is {}, but weights have to be 0 or more",
+					pair[0],
+					weight,
+				) ) );
+			}
+
+			items.push(item);
+			weights.push(weight);
+		}
+
+		let table = AliasTable::new(&weights)
+			.ok_or_else( || ProviderError::InvalidWeights(
+				"every weight is 0, at least one value has to be generated".to_string(),
+			) )?;
+
+		Ok( Self {
+			rng: data.rng(),
+			items,
+			weights,
+			table,
+		} )
+	}
+
+	fn parameters() -> Vec<Parameter> {
+		vec![
+			Parameter::new("options", ParameterKind::Any).variadic(),
+		]
+	}
+
+	fn distinct_values(&self) -> Option<usize> {
+		// values with a weight of 0 are never provided
+		let distinct: HashSet<String> = self.items.iter()
+			.zip(&self.weights)
+			.filter( |(_, weight)| **weight > 0.0 )
+			.map( |(item, _)| item.to_string() )
+			.collect();
+
+		Some( distinct.len() )
+	}
+
+	fn provided_type(&self) -> Option<ColumnType> {
+		common_kind(&self.items)
+	}
+
+	fn provide(&mut self) -> Result<CellValue, ProviderError> {
+		let selected = self.table.sample( &mut self.rng );
+
+		Ok( self.items[selected].clone() )
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn pair(value: &str, weight: i64) -> [Argument; 2] { // {{{
+		[ Argument::String( value.to_string() ), Argument::Int(weight) ]
+	} // }}}
+
+	#[test]
+	fn test_provide_should_skip_values_with_a_weight_of_0() -> Result<(), ProviderError> { // {{{
+		let mut sut = WeightedProvider::new( &ProviderCreationData::with_arguments( [ pair("active", 0), pair("closed", 1) ].concat() ) )?;
+
+		assert_eq!( CellValue::String( "closed".to_string() ), sut.provide()? );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_distinct_values_should_not_count_values_with_a_weight_of_0() -> Result<(), ProviderError> { // {{{
+		let sut = WeightedProvider::new( &ProviderCreationData::with_arguments(
+			[ pair("active", 90), pair("suspended", 0), pair("closed", 1) ].concat()
+		) )?;
+
+		assert_eq!( Some(2), sut.distinct_values() );
+
+		Ok(())
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_for_invalid_weights() { // {{{
+		let missing = WeightedProvider::new( &ProviderCreationData::with_arguments( vec![
+			Argument::String( "active".to_string() ),
+			Argument::Int(1),
+			Argument::String( "closed".to_string() ),
+		] ) );
+		let negative = WeightedProvider::new( &ProviderCreationData::with_arguments( [ pair("active", 1), pair("closed", -1) ].concat() ) );
+		let zero = WeightedProvider::new( &ProviderCreationData::with_arguments( [ pair("active", 0), pair("closed", 0) ].concat() ) );
+
+		assert!( matches!( missing.err(), Some( ProviderError::InvalidWeights(_) ) ) );
+		assert!( matches!( negative.err(), Some( ProviderError::InvalidWeights(_) ) ) );
+		assert!( matches!( zero.err(), Some( ProviderError::InvalidWeights(_) ) ) );
+	} // }}}
+
+	#[test]
+	fn test_new_should_return_error_when_a_weight_is_no_number() { // {{{
+		let result = WeightedProvider::new( &ProviderCreationData::with_arguments( vec![
+			Argument::String( "active".to_string() ),
+			Argument::String( "90".to_string() ),
+		] ) );
+
+		assert!( matches!( result.err(), Some( ProviderError::UnexpectedArgument(_, _) ) ) );
+	} // }}}
+}
@@ -1,6 +1,8 @@
 use thiserror::Error;
 use std::collections::HashMap;
 
+use crate::parameter::Parameter;
+
 pub mod registrars;
 
 #[derive(Debug, Error)]
@@ -9,67 +11,74 @@ pub enum RegistryError<E> {
 	DuplicateItem(String),
 	#[error("Unknown creator '{0}'")]
 	UnknownCreator(String),
-	#[error("Error while creating: {0}")]
+	#[error("{0}")]
 	CreationError(E),
-	#[error("An unknown error occurred: {0}")]
-	Unknown(String),
 }
 
 type CreateFn<R, D, E> = fn(data: &D) -> Result<R, E>;
 
 pub struct Registry<R, D, E> {
-	creation_data: D,
 	creators: HashMap< String, CreateFn<R, D, E> >,
-	items: HashMap<String, R>,
+	parameters: HashMap< String, Vec<Parameter> >,
 }
 
 impl<R, D, E> Registry<R, D, E> {
-	pub fn new(creation_data: D) -> Self {
+	pub fn new() -> Self {
 		Self {
-			creation_data,
 			creators: HashMap::new(),
-			items: HashMap::new(),
+			parameters: HashMap::new(),
 		}
 	}
 
-	pub fn get(&mut self, name: impl ToString) -> Result< &mut R, RegistryError<E> > {
-		let name = name.to_string();
-
-		if self.items.contains_key(&name) {
-			let item = self.items.get_mut(&name).unwrap();
-
-			return Ok(item);
-		}
-
-		// the item doesn't exist yet, so create it
-		let creator = self.creators.get(&name);
-
-		if creator.is_none() {
-			return Err( RegistryError::UnknownCreator(name) );
-		}
-
-		let creator = creator.unwrap();
-
-		let item = creator(&self.creation_data)
-			.map_err( |e| RegistryError::CreationError(e) )?;
-
-		self.items.insert( name.clone(), item );
-
-		let item = self.items.get_mut(&name)
-			.ok_or_else( || RegistryError::Unknown( "Item wasn't found after creating it".to_string() ) )?;
+	/// Creates a new item using the creator registered as `name`. Every call
+	/// returns a fresh item, so items don't share any state.
+	///
+	/// # Arguments
+	///
+	/// - `name` The name the creator was registered as
+	/// - `data` The data the item is created from
+	pub fn create(&self, name: &str, data: &D) -> Result< R, RegistryError<E> > {
+		let creator = self.creators.get(name)
+			.ok_or_else( || RegistryError::UnknownCreator( name.to_string() ) )?;
+
+		creator(data)
+			.map_err( |e| RegistryError::CreationError(e) )
+	}
 
-		Ok(item)
+	/// Returns the parameters of the creator registered as `name`.
+	pub fn parameters(&self, name: &str) -> Result< &[Parameter], RegistryError<E> > {
+		self.parameters.get(name)
+			.map( |parameters| parameters.as_slice() )
+			.ok_or_else( || RegistryError::UnknownCreator( name.to_string() ) )
 	}
 
+	/// Registers a creator without parameters, so its items don't accept any
+	/// arguments.
 	pub fn register(&mut self, name: impl ToString, creator: CreateFn<R, D, E>) -> Result< (), RegistryError<E> > {
+		self.register_with_parameters(name, creator, Vec::new())
+	}
+
+	pub fn register_with_parameters(
+		&mut self,
+		name: impl ToString,
+		creator: CreateFn<R, D, E>,
+		parameters: Vec<Parameter>,
+	) -> Result< (), RegistryError<E> > {
 		let name = name.to_string();
 
 		if self.creators.contains_key(&name) {
 			return Err( RegistryError::DuplicateItem(name) );
 		}
 
-		self.creators.insert(name, creator);
+		self.creators.insert(name.clone(), creator);
+		self.parameters.insert(name, parameters);
 
 		Ok(())
 	}
 }
+
+impl<R, D, E> Default for Registry<R, D, E> { // {{{
+	fn default() -> Self {
+		Self::new()
+	}
+} // }}}
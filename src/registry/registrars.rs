@@ -1,73 +1,170 @@
 use crate::{
-	arguments::Args,
+	constraint::{
+		ConstraintCreationData,
+		ConstraintError,
+		ConstraintImpl,
+	},
+	constraints::{
+		null::{ NullConstraint, NULL_CONSTRAINT_NAME },
+		primary::{ PrimaryConstraint, PRIMARY_CONSTRAINT_NAME },
+		unique::{ UniqueConstraint, UNIQUE_CONSTRAINT_NAME },
+	},
 	provider::{
 		ProviderCreationData,
 		ProviderError,
 		ProviderImpl,
 	},
 	providers::{
-		first_name::FirstNameProvider,
+		date::DateProvider,
+		date_time::DateTimeProvider,
+		decimal::DecimalProvider,
+		enumeration::{ EnumProvider, ENUM_PROVIDER_NAME },
 		gender::GenderProvider,
+		link::{ LinkProvider, LINK_PROVIDER_NAME },
+		list::{ ListProvider, FIRST_NAMES_FILE, LAST_NAMES_FILE },
+		name::NameProvider,
 		random::RandomProvider,
 		number::NumberProvider,
 		row::RowProvider,
+		time::TimeProvider,
+		weighted::WeightedProvider,
 	},
 	generator::{
 		GeneratorCreationData,
 		GeneratorError,
 		GeneratorImpl,
 	},
-	generators::tsql::TsqlGenerator,
+	generators::{
+		csv::CsvGenerator,
+		json::JsonGenerator,
+		tsql::TsqlGenerator,
+		xml::XmlGenerator,
+	},
 	registry::{ Registry, RegistryError },
 };
 
-pub fn register_providers(args: &Args) -> Result<
-	Registry< Box<dyn ProviderImpl>, ProviderCreationData, ProviderError>,
-	RegistryError<ProviderError>,
-> {
-	let creation_data = ProviderCreationData {
-		row_count: args.row_count,
-	};
+pub type ProviderRegistry = Registry< Box<dyn ProviderImpl>, ProviderCreationData, ProviderError>;
+pub type ConstraintRegistry = Registry< Box<dyn ConstraintImpl>, ConstraintCreationData, ConstraintError>;
 
+pub fn register_providers() -> Result< ProviderRegistry, RegistryError<ProviderError> > {
 	let mut registry: Registry< Box<dyn ProviderImpl>, _, _ >
-		= Registry::new(creation_data);
+		= Registry::new();
 
 	registry.register(
 		"row",
 		|args| Ok( Box::new( RowProvider::new(args)? ) ),
 	)?;
-	registry.register(
+	registry.register_with_parameters(
 		"number",
 		|args| Ok( Box::new( NumberProvider::new(args)? ) ),
+		NumberProvider::parameters(),
 	)?;
-	registry.register(
+	registry.register_with_parameters(
 		"gender",
 		|args| Ok( Box::new( GenderProvider::new(args)? ) ),
+		GenderProvider::parameters(),
 	)?;
-	registry.register(
+	registry.register_with_parameters(
 		"random",
 		|args| Ok( Box::new( RandomProvider::new(args)? ) ),
+		RandomProvider::parameters(),
 	)?;
-	registry.register(
+	registry.register_with_parameters(
+		"weighted",
+		|args| Ok( Box::new( WeightedProvider::new(args)? ) ),
+		WeightedProvider::parameters(),
+	)?;
+	registry.register_with_parameters(
 		"first_name",
-		|args| Ok( Box::new( FirstNameProvider::new(args)? ) ),
+		|args| Ok( Box::new( ListProvider::with_default_file(args, FIRST_NAMES_FILE)? ) ),
+		ListProvider::parameters(),
+	)?;
+	registry.register_with_parameters(
+		"last_name",
+		|args| Ok( Box::new( ListProvider::with_default_file(args, LAST_NAMES_FILE)? ) ),
+		ListProvider::parameters(),
+	)?;
+	registry.register_with_parameters(
+		"name",
+		|args| Ok( Box::new( NameProvider::new(args)? ) ),
+		NameProvider::parameters(),
+	)?;
+	registry.register_with_parameters(
+		LINK_PROVIDER_NAME,
+		|args| Ok( Box::new( LinkProvider::new(args)? ) ),
+		LinkProvider::parameters(),
+	)?;
+	registry.register_with_parameters(
+		"decimal",
+		|args| Ok( Box::new( DecimalProvider::new(args)? ) ),
+		DecimalProvider::parameters(),
+	)?;
+	registry.register_with_parameters(
+		"date",
+		|args| Ok( Box::new( DateProvider::new(args)? ) ),
+		DateProvider::parameters(),
+	)?;
+	registry.register_with_parameters(
+		"time",
+		|args| Ok( Box::new( TimeProvider::new(args)? ) ),
+		TimeProvider::parameters(),
+	)?;
+	registry.register_with_parameters(
+		"date_time",
+		|args| Ok( Box::new( DateTimeProvider::new(args)? ) ),
+		DateTimeProvider::parameters(),
+	)?;
+	registry.register(
+		ENUM_PROVIDER_NAME,
+		|args| Ok( Box::new( EnumProvider::new(args)? ) ),
 	)?;
 
 	Ok(registry)
 }
 
-pub fn register_generators<'a>(_args: &Args) -> Result<
+pub fn register_constraints() -> Result< ConstraintRegistry, RegistryError<ConstraintError> > {
+	let mut registry: Registry< Box<dyn ConstraintImpl>, _, _ >
+		= Registry::new();
+
+	registry.register_with_parameters(
+		NULL_CONSTRAINT_NAME,
+		|args| Ok( Box::new( NullConstraint::new(args)? ) ),
+		NullConstraint::parameters(),
+	)?;
+	registry.register(
+		PRIMARY_CONSTRAINT_NAME,
+		|args| Ok( Box::new( PrimaryConstraint::new(args)? ) ),
+	)?;
+	registry.register(
+		UNIQUE_CONSTRAINT_NAME,
+		|args| Ok( Box::new( UniqueConstraint::new(args)? ) ),
+	)?;
+
+	Ok(registry)
+}
+
+pub fn register_generators() -> Result<
 	Registry< Box<dyn GeneratorImpl>, GeneratorCreationData, GeneratorError>,
 	RegistryError<GeneratorError>,
 > {
-	let creation_data = GeneratorCreationData {};
-
 	let mut registry: Registry< Box<dyn GeneratorImpl>, _, _ >
-		= Registry::new(creation_data);
+		= Registry::new();
 
 	registry.register(
 		"tsql",
-		|_args| Ok( Box::new( TsqlGenerator::new()? ) ),
+		|args| Ok( Box::new( TsqlGenerator::new(args)? ) ),
+	)?;
+	registry.register(
+		"csv",
+		|args| Ok( Box::new( CsvGenerator::new(args)? ) ),
+	)?;
+	registry.register(
+		"json",
+		|args| Ok( Box::new( JsonGenerator::new(args)? ) ),
+	)?;
+	registry.register(
+		"xml",
+		|args| Ok( Box::new( XmlGenerator::new(args)? ) ),
 	)?;
 
 	Ok(registry)
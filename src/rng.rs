@@ -0,0 +1,93 @@
+use rand::{
+	rngs::StdRng,
+	RngCore,
+	SeedableRng,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Hashes `parts` using FNV-1a. Used instead of [`std::hash::Hash`], because
+/// the result has to be the same for every build of the program.
+fn hash(parts: &[&[u8]]) -> u64 { // {{{
+	let mut hash = FNV_OFFSET_BASIS;
+
+	for part in parts {
+		for byte in part.iter() {
+			hash ^= *byte as u64;
+			hash = hash.wrapping_mul(FNV_PRIME);
+		}
+
+		// separate the parts, so ("ab", "c") and ("a", "bc") differ
+		hash ^= 0xff;
+		hash = hash.wrapping_mul(FNV_PRIME);
+	}
+
+	hash
+} // }}}
+
+/// Creates a random number generator for a single column. When `seed` is
+/// given, the generator is seeded with a combination of the seed and `names`,
+/// so every column gets its own sequence of values that doesn't change when
+/// other columns are added or removed. Without a seed, the generator is
+/// seeded randomly.
+///
+/// # Arguments
+///
+/// - `seed` The seed given on the command line
+/// - `names` The names identifying the column, for example the table and
+///   column name
+pub fn column_rng(seed: Option<u64>, names: &[&str]) -> Box<dyn RngCore> { // {{{
+	let Some(seed) = seed else {
+		return Box::new( rand::thread_rng() );
+	};
+
+	let seed_bytes = seed.to_le_bytes();
+	let mut parts: Vec<&[u8]> = Vec::with_capacity( names.len() + 1 );
+
+	parts.push(&seed_bytes);
+	parts.extend( names.iter().map( |name| name.as_bytes() ) );
+
+	Box::new( StdRng::seed_from_u64( hash(&parts) ) )
+} // }}}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn first_values(mut rng: Box<dyn RngCore>) -> Vec<u64> { // {{{
+		(0..5).map( |_| rng.next_u64() ).collect()
+	} // }}}
+
+	#[test]
+	fn test_column_rng_should_return_the_same_values_for_the_same_seed() { // {{{
+		let first = first_values( column_rng( Some(42), &["Table", "column"] ) );
+		let second = first_values( column_rng( Some(42), &["Table", "column"] ) );
+
+		assert_eq!(first, second);
+	} // }}}
+
+	#[test]
+	fn test_column_rng_should_return_different_values_for_different_columns() { // {{{
+		let first = first_values( column_rng( Some(42), &["Table", "column"] ) );
+		let second = first_values( column_rng( Some(42), &["Table", "other_column"] ) );
+
+		assert_ne!(first, second);
+	} // }}}
+
+	#[test]
+	fn test_column_rng_should_return_different_values_for_different_seeds() { // {{{
+		let first = first_values( column_rng( Some(42), &["Table", "column"] ) );
+		let second = first_values( column_rng( Some(43), &["Table", "column"] ) );
+
+		assert_ne!(first, second);
+	} // }}}
+
+	#[test]
+	fn test_hash_should_separate_the_parts() { // {{{
+		assert_ne!(
+			hash( &[ b"ab", b"c" ] ),
+			hash( &[ b"a", b"bc" ] ),
+		);
+	} // }}}
+}
@@ -4,22 +4,35 @@ endif
 
 " comments
 syntax match mockComment "//.*$"
+syntax region mockComment start="/\*" end="\*/"
+syntax match mockDocComment "///\(/\)\@!.*$"
 
 " common highlights
 syntax keyword mockBoolean true false
-syntax match mockNumber "-\?\d\+"
+syntax match mockNumber "[-+]\?\d\+"
+syntax match mockFloat "[-+]\?\d\+\(\.\d\+\)\?[eE][-+]\?\d\+"
+syntax match mockFloat "[-+]\?\d\+\.\d\+"
 
-syntax keyword mockType int uint float boolean string
+syntax keyword mockType int uint float boolean string decimal date time date_time
 
 syntax region mockString start="\"" end="\"" skip="\\\"" contains=mockCharacter
 syntax match mockCharacter "\\." contained
 
+" imports
+syntax keyword mockInclude import
+
+" constants
+syntax keyword mockStatement let
+syntax match mockConstant "\<\u[A-Z0-9_]*\>"
+
 " table definition
 syntax match mockStatement "^\<table\> \+\ze\w\+" nextgroup=mockTableName
+syntax match mockStatement "^\<template\> \+\ze\w\+" nextgroup=mockTableName
+syntax match mockStatement "^\<enum\> \+\ze\w\+" nextgroup=mockTableName
 syntax match mockTableName "\w\+" contained
 
 " column definition
-syntax region mockTableDefinition start="{" end="}" fold transparent contains=mockColumn,mockFunction,mockProvider,mockBoolean,mockNumber,mockString,mockType,mockComment
+syntax region mockTableDefinition start="{" end="}" fold transparent contains=mockColumn,mockFunction,mockProvider,mockBoolean,mockNumber,mockFloat,mockString,mockType,mockComment,mockConstant
 syntax match mockColumn "^\s*\w\+\ze" contained
 syntax match mockFunction "\$\w\+\ze\(\w*\)"
 syntax match mockProvider "#\w\+\ze\(\w*\)"
@@ -27,6 +40,7 @@ syntax match mockProvider "#\w\+\ze\(\w*\)"
 let b:current_syntax = "mock"
 
 hi def link mockComment Comment
+hi def link mockDocComment SpecialComment
 
 hi def link mockBoolean Boolean
 hi def link mockNumber Number
@@ -34,7 +48,9 @@ hi def link mockFloat Float
 hi def link mockString String
 hi def link mockCharacter Character
 hi def link mockType Type
+hi def link mockConstant Constant
 
+hi def link mockInclude Include
 hi def link mockStatement Statement
 hi def link mockTableName Identifier
 hi def link mockColumn Identifier